@@ -0,0 +1,175 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use crate::{Outcome, Task};
+
+const ANSWERS_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml");
+
+/// Known-good answers per day and part, read from a TOML file like:
+///
+/// ```toml
+/// [day1]
+/// part1 = 1150
+/// part2 = "6738"
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, Task), String>,
+}
+
+#[derive(Debug)]
+pub struct AnswersError {
+    path: PathBuf,
+    line: usize,
+    message: String,
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self.line {
+            0 => write!(formatter, "{}: {}", self.path.display(), self.message),
+            line => write!(formatter, "{}:{line}: {}", self.path.display(), self.message),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+/// Answer that differs from the one stored in answers file
+#[derive(Debug, PartialEq)]
+pub struct Mismatch {
+    pub day: u8,
+    pub task: Task,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        write!(
+            formatter,
+            "day{} {}: expected {} but got {}",
+            self.day, self.task, self.expected, self.actual
+        )
+    }
+}
+
+pub fn default_answers_path() -> PathBuf {
+    PathBuf::from(ANSWERS_FILE)
+}
+
+impl Answers {
+    pub fn read(path: &Path) -> Result<Self, AnswersError> {
+        let content = std::fs::read_to_string(path).map_err(|error| AnswersError {
+            path: path.into(),
+            line: 0,
+            message: error.to_string(),
+        })?;
+
+        Self::parse(&content).map_err(|(line, message)| AnswersError {
+            path: path.into(),
+            line,
+            message,
+        })
+    }
+
+    fn parse(content: &str) -> Result<Self, (usize, String)> {
+        let mut answers = BTreeMap::new();
+        let mut day = None;
+
+        for (index, line) in content.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.split_once('#').map_or(line, |(line, _)| line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(section) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+                day = Some(
+                    section.trim().strip_prefix("day")
+                        .and_then(|number| number.parse::<u8>().ok())
+                        .ok_or_else(|| (line_number, format!("unknown section `{section}`")))?
+                );
+                continue;
+            }
+
+            let (key, value) = line.split_once('=')
+                .ok_or_else(|| (line_number, format!("expected `key = value`, found `{line}`")))?;
+            let (key, value) = (key.trim(), value.trim());
+            let task = key.strip_prefix("part")
+                .and_then(|number| number.parse::<u8>().ok())
+                .and_then(Task::from_number)
+                .ok_or_else(|| (line_number, format!("unknown key `{key}`")))?;
+            let day = day.ok_or_else(|| (line_number, format!("`{key}` outside of a [dayN] section")))?;
+            let value = match value.strip_prefix('"').and_then(|value| value.strip_suffix('"')) {
+                Some(value) => value,
+                None if !value.is_empty() && value.bytes().all(|byte| byte.is_ascii_digit() || byte == b'-') => value,
+                None => return Err((line_number, format!("invalid answer `{value}`"))),
+            };
+
+            answers.insert((day, task), value.to_string());
+        }
+
+        Ok(Self { answers })
+    }
+
+    pub fn get(&self, day: u8, task: Task) -> Option<&str> {
+        self.answers.get(&(day, task)).map(String::as_str)
+    }
+
+    /// Compares outcomes against known answers, parts without a stored answer and failed parts are skipped
+    pub fn verify(&self, outcomes: &[Outcome]) -> Vec<Mismatch> {
+        outcomes.iter().filter_map(|outcome| {
+            let expected = self.get(outcome.day, outcome.task)?;
+            let actual = outcome.answer.as_ref().ok()?;
+            (expected != actual).then(|| Mismatch {
+                day: outcome.day,
+                task: outcome.task,
+                expected: expected.to_string(),
+                actual: actual.clone(),
+            })
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Statistics;
+    use super::*;
+
+    fn outcome(day: u8, task: Task, answer: &str) -> Outcome {
+        Outcome { day, task, answer: Ok(answer.into()), parse: Statistics::default(), solve: Statistics::default() }
+    }
+
+    #[test]
+    fn parses_answers() {
+        let answers = Answers::parse("# known answers\n[day1]\npart1 = 1150\npart2 = \"6738\" # verified\n\n[day12]\npart1 = -5\n").unwrap();
+
+        assert_eq!(answers.get(1, Task::Part1), Some("1150"));
+        assert_eq!(answers.get(1, Task::Part2), Some("6738"));
+        assert_eq!(answers.get(12, Task::Part1), Some("-5"));
+        assert_eq!(answers.get(12, Task::Part2), None);
+    }
+
+    #[test]
+    fn reports_line_of_invalid_entry() {
+        assert_eq!(Answers::parse("part1 = 1").unwrap_err(), (1, "`part1` outside of a [dayN] section".into()));
+        assert_eq!(Answers::parse("[day1]\npart3 = 1").unwrap_err(), (2, "unknown key `part3`".into()));
+        assert_eq!(Answers::parse("[day1]\n\npart1 = abc").unwrap_err(), (3, "invalid answer `abc`".into()));
+        assert_eq!(Answers::parse("[first]").unwrap_err(), (1, "unknown section `first`".into()));
+    }
+
+    #[test]
+    fn verifies_outcomes_against_answers() {
+        let answers = Answers::parse("[day4]\npart1 = 13\npart2 = 43\n").unwrap();
+
+        assert_eq!(
+            answers.verify(&[
+                outcome(4, Task::Part1, "13"),
+                outcome(4, Task::Part2, "42"),
+                outcome(5, Task::Part1, "3"),
+            ]),
+            vec![Mismatch { day: 4, task: Task::Part2, expected: "43".into(), actual: "42".into() }]
+        );
+    }
+}
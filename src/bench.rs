@@ -0,0 +1,148 @@
+use std::fmt::Display;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// How many times an operation runs untimed to warm up caches and how many timed samples follow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bench {
+    pub warmup: usize,
+    pub samples: usize,
+}
+
+impl Bench {
+    pub const ONCE: Bench = Bench { warmup: 0, samples: 1 };
+
+    /// Collects given number of samples after warming up for a tenth of them
+    pub fn new(samples: usize) -> Self {
+        Self {
+            warmup: (samples / 10).max(1),
+            samples: samples.max(1),
+        }
+    }
+}
+
+impl Default for Bench {
+    fn default() -> Self {
+        Self::ONCE
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Statistics {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p99: Duration,
+}
+
+impl Statistics {
+    pub fn single(elapsed: Duration) -> Self {
+        Self::from_samples(&mut [elapsed])
+    }
+
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+
+        samples.sort_unstable();
+        let count = samples.len();
+        let median = match count % 2 {
+            0 => (samples[count / 2 - 1] + samples[count / 2]) / 2,
+            _ => samples[count / 2],
+        };
+        let total = samples.iter().sum::<Duration>();
+
+        Self {
+            samples: count,
+            min: samples[0],
+            median,
+            mean: total / count as u32,
+            p99: samples[(count * 99).div_ceil(100) - 1],
+        }
+    }
+}
+
+impl Display for Statistics {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        write!(
+            formatter,
+            "min {:.2?}, median {:.2?}, mean {:.2?}, p99 {:.2?} over {} samples",
+            self.min, self.median, self.mean, self.p99, self.samples
+        )
+    }
+}
+
+pub struct Measurement<R> {
+    pub result: R,
+    pub timing: Statistics,
+}
+
+/// Runs operation according to bench settings and keeps the result of the last run
+pub fn measure<F: FnMut() -> R, R>(bench: Bench, mut operation: F) -> Measurement<R> {
+    for _ in 0..bench.warmup {
+        black_box(operation());
+    }
+
+    let mut samples = Vec::with_capacity(bench.samples.max(1));
+    let mut result = None;
+    for _ in 0..bench.samples.max(1) {
+        let start = Instant::now();
+        let value = black_box(operation());
+        samples.push(start.elapsed());
+        result = Some(value);
+    }
+
+    Measurement {
+        result: result.expect("at least one sample is always taken"),
+        timing: Statistics::from_samples(&mut samples),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calculates_statistics_from_samples() {
+        let mut samples = (1..=100).rev().map(Duration::from_micros).collect::<Vec<_>>();
+
+        assert_eq!(
+            Statistics::from_samples(&mut samples),
+            Statistics {
+                samples: 100,
+                min: Duration::from_micros(1),
+                median: Duration::from_nanos(50_500),
+                mean: Duration::from_nanos(50_500),
+                p99: Duration::from_micros(99),
+            }
+        );
+    }
+
+    #[test]
+    fn calculates_statistics_of_odd_samples() {
+        let mut samples = [5, 1, 3].map(Duration::from_nanos);
+
+        let statistics = Statistics::from_samples(&mut samples);
+        assert_eq!(statistics.median, Duration::from_nanos(3));
+        assert_eq!(statistics.p99, Duration::from_nanos(5));
+    }
+
+    #[test]
+    fn runs_warmup_and_samples() {
+        let mut calls = 0;
+        let measurement = measure(Bench { warmup: 2, samples: 5 }, || {
+            calls += 1;
+            calls
+        });
+
+        assert_eq!(measurement.result, 7);
+        assert_eq!(measurement.timing.samples, 5);
+    }
+
+    #[test]
+    fn takes_at_least_one_sample() {
+        assert_eq!(measure(Bench { warmup: 0, samples: 0 }, || 42).result, 42);
+    }
+}
@@ -0,0 +1,174 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+use aoc2025::days::{self, Day, DAYS};
+use aoc2025::{compare, default_answers_path, default_history_path, find_baseline, read_history, Answers, Bench, ComparisonReport, Format, InputSource, Report, Run, Task};
+
+const USAGE: &str = "usage: aoc <day|all> [--part <1|2>] [--input <path|->] [--verify] [--answers <path>] [--bench <samples>] [--warmup <runs>] [--format <table|json|csv>]\n           [--save] [--history <path>] [--baseline <commit|latest>] [--threshold <percent>]";
+
+struct Options {
+    days: Vec<&'static Day>,
+    tasks: Vec<Task>,
+    input: Option<String>,
+    answers: Option<PathBuf>,
+    bench: Bench,
+    format: Format,
+    save: bool,
+    history: PathBuf,
+    baseline: Option<String>,
+    threshold: f64,
+}
+
+fn parse_options(mut arguments: impl Iterator<Item=String>) -> Result<Options, String> {
+    let days = match arguments.next().as_deref() {
+        Some("all") => DAYS.iter().collect(),
+        Some(day) => {
+            let number = day.parse::<u8>().map_err(|_| format!("invalid day `{day}`"))?;
+            vec![days::find(number).ok_or_else(|| format!("day {number} is not solved yet"))?]
+        },
+        None => return Err(USAGE.into()),
+    };
+
+    let mut options = Options {
+        days,
+        tasks: vec![Task::Part1, Task::Part2],
+        input: None,
+        answers: None,
+        bench: Bench::ONCE,
+        format: Format::Table,
+        save: false,
+        history: default_history_path(),
+        baseline: None,
+        threshold: 10.0,
+    };
+    let mut warmup = None;
+
+    while let Some(argument) = arguments.next() {
+        let mut value = || arguments.next().ok_or_else(|| format!("missing value for `{argument}`"));
+        match argument.as_str() {
+            "--part" => {
+                let value = value()?;
+                let task = value.parse::<u8>().ok().and_then(Task::from_number)
+                    .ok_or_else(|| format!("invalid part `{value}`"))?;
+                options.tasks = vec![task];
+            },
+            "--input" if options.days.len() == 1 => options.input = Some(value()?),
+            "--input" => return Err("`--input` requires a single day".into()),
+            "--verify" => options.answers = Some(default_answers_path()),
+            "--answers" => options.answers = Some(value()?.into()),
+            "--bench" => {
+                let value = value()?;
+                let samples = value.parse::<usize>().map_err(|_| format!("invalid number of samples `{value}`"))?;
+                options.bench = Bench::new(samples);
+            },
+            "--warmup" => {
+                let value = value()?;
+                warmup = Some(value.parse::<usize>().map_err(|_| format!("invalid number of runs `{value}`"))?);
+            },
+            "--format" => {
+                let value = value()?;
+                options.format = Format::from_name(&value).ok_or_else(|| format!("unknown format `{value}`"))?;
+            },
+            "--save" => options.save = true,
+            "--history" => options.history = value()?.into(),
+            "--baseline" => options.baseline = Some(value()?),
+            "--threshold" => {
+                let value = value()?;
+                options.threshold = value.trim_end_matches('%').parse::<f64>()
+                    .map_err(|_| format!("invalid threshold `{value}`"))?;
+            },
+            _ => return Err(format!("unknown option `{argument}`\n{USAGE}")),
+        }
+    }
+
+    if let Some(warmup) = warmup {
+        options.bench.warmup = warmup;
+    }
+
+    Ok(options)
+}
+
+fn main() -> ExitCode {
+    let options = match parse_options(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let answers = match options.answers.as_deref().map(Answers::read).transpose() {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let baseline = match options.baseline.as_deref() {
+        Some(reference) => match read_history(&options.history) {
+            Ok(runs) => match find_baseline(&runs, reference) {
+                Some(run) => Some(run.clone()),
+                None => {
+                    eprintln!("no saved run matches baseline `{reference}`");
+                    return ExitCode::from(2);
+                }
+            },
+            Err(error) => {
+                eprintln!("{error}");
+                return ExitCode::from(2);
+            }
+        },
+        None => None,
+    };
+
+    let mut outcomes = Vec::new();
+    let mut status = ExitCode::SUCCESS;
+
+    for day in options.days {
+        match InputSource::new(day.number, options.input.as_deref()).read() {
+            Ok(input) => match day.run(&input, &options.tasks, options.bench) {
+                Ok(day_outcomes) => outcomes.extend(day_outcomes),
+                Err(error) => {
+                    eprintln!("{error}");
+                    status = ExitCode::FAILURE;
+                }
+            },
+            Err(error) => {
+                eprintln!("{error}");
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    for error in outcomes.iter().filter_map(|outcome| outcome.answer.as_ref().err()) {
+        eprintln!("{error}");
+        status = ExitCode::FAILURE;
+    }
+
+    print!("{}", Report::new(&outcomes, options.format));
+
+    if let Some(answers) = answers {
+        let mismatches = answers.verify(&outcomes);
+        for mismatch in &mismatches {
+            eprintln!("{mismatch}");
+        }
+        if !mismatches.is_empty() {
+            status = ExitCode::FAILURE;
+        }
+    }
+
+    if let Some(baseline) = baseline {
+        let comparisons = compare(&baseline, &outcomes, options.threshold);
+        eprint!("{}", ComparisonReport { baseline: &baseline, comparisons: &comparisons });
+        if comparisons.iter().any(|comparison| comparison.regression) {
+            status = ExitCode::FAILURE;
+        }
+    }
+
+    if options.save && let Err(error) = Run::current(outcomes).append(&options.history) {
+        eprintln!("{error}");
+        status = ExitCode::FAILURE;
+    }
+
+    status
+}
@@ -0,0 +1,142 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+use aoc2025::days::day4::{remove_in_queue_waves, remove_in_waves, Day4, Rule, Wave};
+use aoc2025::{InputSource, Solution};
+
+const USAGE: &str = "usage: day4-frames [--input <path|->] [--order <waves|queue>] [--format <ansi|text|ppm>] [--output <directory>] [--delay <ms>]";
+
+/// How removal waves are shown
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    /// Redraws the terminal for every wave
+    Ansi,
+    Text,
+    Ppm,
+}
+
+/// Which removal the waves come from
+#[derive(Clone, Copy, PartialEq)]
+enum Order {
+    /// Every accessible roll at once, as part 2 is solved
+    Waves,
+    /// The work queue, a roll can go in the same wave as the neighbour that freed it up
+    Queue,
+}
+
+struct Options {
+    input: Option<String>,
+    order: Order,
+    format: Format,
+    output: Option<PathBuf>,
+    delay: Duration,
+}
+
+fn parse_options(mut arguments: impl Iterator<Item=String>) -> Result<Options, String> {
+    let mut options = Options {
+        input: None,
+        order: Order::Waves,
+        format: Format::Ansi,
+        output: None,
+        delay: Duration::from_millis(200),
+    };
+
+    while let Some(argument) = arguments.next() {
+        let mut value = || arguments.next().ok_or_else(|| format!("missing value for `{argument}`"));
+        match argument.as_str() {
+            "--input" => options.input = Some(value()?),
+            "--order" => {
+                let value = value()?;
+                options.order = match value.as_str() {
+                    "waves" => Order::Waves,
+                    "queue" => Order::Queue,
+                    _ => return Err(format!("unknown order `{value}`")),
+                };
+            },
+            "--format" => {
+                let value = value()?;
+                options.format = match value.as_str() {
+                    "ansi" => Format::Ansi,
+                    "text" => Format::Text,
+                    "ppm" => Format::Ppm,
+                    _ => return Err(format!("unknown format `{value}`")),
+                };
+            },
+            "--output" => options.output = Some(value()?.into()),
+            "--delay" => {
+                let value = value()?;
+                options.delay = Duration::from_millis(value.parse().map_err(|_| format!("invalid delay `{value}`"))?);
+            },
+            _ => return Err(format!("unknown option `{argument}`\n{USAGE}")),
+        }
+    }
+
+    if options.format != Format::Ansi && options.output.is_none() {
+        return Err("text and ppm frames need an `--output` directory".into());
+    }
+
+    Ok(options)
+}
+
+fn main() -> ExitCode {
+    let options = match parse_options(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let input = match InputSource::new(Day4::DAY, options.input.as_deref()).read() {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut grid = match Day4::parse(&input) {
+        Ok(grid) => grid,
+        Err(error) => {
+            eprintln!("{}", error.in_day(Day4::DAY));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Some(output) = &options.output
+        && let Err(error) = std::fs::create_dir_all(output) {
+        eprintln!("{}: {error}", output.display());
+        return ExitCode::FAILURE;
+    }
+
+    let mut status = Ok(());
+    let mut on_wave = |wave: &Wave| {
+        if status.is_err() {
+            return;
+        }
+        let (extension, frame) = match options.format {
+            Format::Ansi => {
+                print!("\x1b[2J\x1b[H{wave}");
+                std::thread::sleep(options.delay);
+                return;
+            },
+            Format::Text => ("txt", wave.to_string().into_bytes()),
+            Format::Ppm => ("ppm", wave.to_ppm()),
+        };
+
+        let path = options.output.as_deref().unwrap_or(".".as_ref()).join(format!("wave-{:04}.{extension}", wave.number));
+        println!("wave {}: removed {} rolls", wave.number, wave.count());
+        status = std::fs::write(&path, frame).map_err(|error| format!("{}: {error}", path.display()));
+    };
+    let total = match options.order {
+        Order::Waves => remove_in_waves(&mut grid, Rule::PUZZLE, &mut on_wave),
+        Order::Queue => remove_in_queue_waves(&mut grid, Rule::PUZZLE, &mut on_wave),
+    };
+
+    if let Err(message) = status {
+        eprintln!("{message}");
+        return ExitCode::FAILURE;
+    }
+
+    println!("removed {total} rolls");
+    ExitCode::SUCCESS
+}
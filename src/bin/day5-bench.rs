@@ -0,0 +1,93 @@
+use std::process::ExitCode;
+use aoc2025::days::day5::{count_fresh, count_fresh_scanning, Day5};
+use aoc2025::{measure, xorshift, Bench, Solution};
+
+const USAGE: &str = "usage: day5-bench [--ranges <count>] [--ids <count>] [--samples <count>] [--seed <number>] [--scan]";
+
+struct Options {
+    ranges: usize,
+    ids: usize,
+    samples: usize,
+    seed: u64,
+    /// Also times the scan of every range, only practical for small inputs
+    scan: bool,
+}
+
+fn parse_options(mut arguments: impl Iterator<Item=String>) -> Result<Options, String> {
+    let mut options = Options {
+        ranges: 1_000_000,
+        ids: 1_000_000,
+        samples: 10,
+        seed: 0x2545_f491_4f6c_dd1d,
+        scan: false,
+    };
+
+    while let Some(argument) = arguments.next() {
+        let mut number = || {
+            let value = arguments.next().ok_or_else(|| format!("missing value for `{argument}`"))?;
+            value.replace('_', "").parse::<u64>().map_err(|_| format!("invalid number `{value}`"))
+        };
+        match argument.as_str() {
+            "--ranges" => options.ranges = number()? as usize,
+            "--ids" => options.ids = number()? as usize,
+            "--samples" => options.samples = number()? as usize,
+            "--seed" => options.seed = number()?.max(1),
+            "--scan" => options.scan = true,
+            _ => return Err(format!("unknown option `{argument}`\n{USAGE}")),
+        }
+    }
+
+    Ok(options)
+}
+
+/// Puzzle input with random ranges spread so that roughly two in five random IDs are fresh
+fn generate(options: &Options) -> String {
+    let mut state = options.seed;
+    let mut next = || xorshift(&mut state);
+    let space = 1u64 << 40;
+    let width = space / options.ranges.max(1) as u64;
+
+    let mut input = String::new();
+    for _ in 0..options.ranges {
+        let start = next() % space;
+        input += &format!("{start}-{}\n", start + next() % width.max(1));
+    }
+    input += "\n";
+    for _ in 0..options.ids {
+        input += &format!("{}\n", next() % space);
+    }
+
+    input
+}
+
+fn main() -> ExitCode {
+    let options = match parse_options(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let input = generate(&options);
+    let (ranges, ids) = match Day5::parse(input.as_bytes()) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            eprintln!("{}", error.in_day(Day5::DAY));
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("{} ranges, {} IDs", ranges.len(), ids.len());
+
+    let bench = Bench::new(options.samples);
+    let search = measure(bench, || count_fresh(&ranges, &ids));
+    println!("binary search: {} fresh, {}", search.result, search.timing);
+
+    if options.scan {
+        let scan = measure(bench, || count_fresh_scanning(&ranges, &ids));
+        println!("scan:          {} fresh, {}", scan.result, scan.timing);
+        println!("speedup:       {:.1}x", scan.timing.median.as_secs_f64() / search.timing.median.as_secs_f64());
+    }
+
+    ExitCode::SUCCESS
+}
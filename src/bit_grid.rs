@@ -0,0 +1,539 @@
+use std::fmt::Display;
+use std::simd::prelude::*;
+use crate::{Error, Grid, Lines};
+
+const CHUNK: usize = 256;
+
+/// Grid of flags packed into rows of 256 bit chunks, column `c` is bit `c % 64` of word `c / 64`
+///
+/// Bits past the width are always clear, so whole rows can be shifted and counted without masking.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    chunks: usize,
+    cells: Vec<u64x4>,
+}
+
+impl BitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        let chunks = width.div_ceil(CHUNK);
+        Self { width, height, chunks, cells: vec![u64x4::splat(0); chunks * height] }
+    }
+
+    /// Same rules and errors as [`Grid::parse`] with `set` and `clear` as the only cells, every row is packed
+    /// 64 columns at a time straight from the comparison masks
+    pub fn parse(lines: Lines<'_>, set: u8, clear: u8) -> Result<Self, Error> {
+        let (set, clear) = (u8x64::splat(set), u8x64::splat(clear));
+        let mut grid = BitGrid::new(0, 0);
+
+        for (row, line) in lines.enumerate() {
+            if row == 0 {
+                grid = BitGrid::new(line.len(), 0);
+            }
+            if line.len() != grid.width {
+                return Err(Error::new(format!("row is {} cells wide, expected {}", line.len(), grid.width)).at_line(row + 1));
+            }
+
+            grid.cells.resize((row + 1) * grid.chunks, u64x4::splat(0));
+            for (word, scan) in line.chunks(64).enumerate() {
+                let bytes = u8x64::load_or(scan, clear);
+                let is_set = bytes.simd_eq(set);
+                if let Some(column) = (!(is_set | bytes.simd_eq(clear))).first_set() {
+                    return Err(Error::new(format!("unexpected cell '{}'", scan[column].escape_ascii())).at(row + 1, word * 64 + column + 1));
+                }
+                grid.cells[row * grid.chunks + word / 4][word % 4] = is_set.to_bitmask();
+            }
+            grid.height += 1;
+        }
+
+        Ok(grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Positions outside of the grid are clear
+    pub fn get(&self, (row, column): (usize, usize)) -> bool {
+        row < self.height && column < self.width && {
+            let chunk = self.cells[row * self.chunks + column / CHUNK];
+            chunk[column % CHUNK / 64] >> (column % 64) & 1 == 1
+        }
+    }
+
+    pub fn set(&mut self, (row, column): (usize, usize), value: bool) {
+        assert!(row < self.height && column < self.width, "position {:?} is outside of a {}x{} grid", (row, column), self.width, self.height);
+        let word = &mut self.cells[row * self.chunks + column / CHUNK][column % CHUNK / 64];
+        let bit = 1 << (column % 64);
+        if value {
+            *word |= bit;
+        } else {
+            *word &= !bit;
+        }
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.cells.iter().map(|chunk| chunk.count_ones().reduce_sum() as usize).sum()
+    }
+
+    /// Set positions, row by row
+    pub fn positions(&self) -> impl Iterator<Item=(usize, usize)> + '_ {
+        (0..self.height).flat_map(move |row| {
+            (0..self.width).filter(move |column| self.get((row, *column))).map(move |column| (row, column))
+        })
+    }
+
+    /// Clears every position set in `other`, which must have the same size
+    pub fn remove(&mut self, other: &BitGrid) {
+        assert_eq!((self.width, self.height), (other.width, other.height), "grids differ in size");
+        for (cell, removed) in self.cells.iter_mut().zip(&other.cells) {
+            *cell &= !*removed;
+        }
+    }
+
+    /// Set positions with fewer than `limit` set positions in their neighbourhood, see [`NeighbourCounter`]
+    /// to count repeatedly on a changing grid
+    pub fn with_fewer_neighbours(&self, limit: usize, neighbourhood: Neighbourhood, edges: Edges) -> BitGrid {
+        NeighbourCounter::new(self, neighbourhood, edges).with_fewer(self, limit)
+    }
+
+    /// Positions in the neighbourhood that are inside of the grid, or wrapped around to the other side
+    pub fn neighbours(&self, (row, column): (usize, usize), neighbourhood: Neighbourhood, edges: Edges) -> impl Iterator<Item=(usize, usize)> + use<> {
+        let (height, width) = (self.height as isize, self.width as isize);
+        neighbourhood.offsets().into_iter().filter_map(move |(row_offset, column_offset)| {
+            let (row, column) = (row as isize + row_offset, column as isize + column_offset);
+            match edges {
+                Edges::Bounded => ((0..height).contains(&row) && (0..width).contains(&column)).then_some((row as usize, column as usize)),
+                Edges::Wrapping => Some((row.rem_euclid(height) as usize, column.rem_euclid(width) as usize)),
+            }
+        })
+    }
+
+    /// Fills `target` so every position holds the one `offset` columns to its right
+    fn shift_columns_into(&self, offset: isize, edges: Edges, target: &mut BitGrid) {
+        let width = self.width as isize;
+        target.cells.fill(u64x4::splat(0));
+
+        for row in 0..self.height {
+            let (source, target) = (self.row(row), &mut target.cells[row * self.chunks..(row + 1) * self.chunks]);
+            match edges {
+                Edges::Bounded => shift_words(source, offset, target),
+                Edges::Wrapping if width > 0 => {
+                    let wrapped = offset.rem_euclid(width);
+                    shift_words(source, wrapped, target);
+                    shift_words(source, wrapped - width, target);
+                },
+                Edges::Wrapping => {},
+            }
+        }
+
+        target.clear_padding();
+    }
+
+    fn clear_padding(&mut self) {
+        if self.width.is_multiple_of(CHUNK) {
+            return;
+        }
+        let valid = self.width % CHUNK;
+        let mask = u64x4::from_array(std::array::from_fn(|word| match (valid as isize - word as isize * 64).clamp(0, 64) {
+            64 => u64::MAX,
+            bits => (1u64 << bits) - 1,
+        }));
+        for row in 0..self.height {
+            self.cells[(row + 1) * self.chunks - 1] &= mask;
+        }
+    }
+
+    fn row(&self, row: usize) -> &[u64x4] {
+        &self.cells[row * self.chunks..(row + 1) * self.chunks]
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        let mut result = BitGrid::new(grid.width(), grid.height());
+        for position in grid.positions().filter(|position| grid[*position]) {
+            result.set(position, true);
+        }
+
+        result
+    }
+}
+
+/// `#` for set and `.` for clear positions, one line per row
+impl Display for BitGrid {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        for row in 0..self.height {
+            for column in 0..self.width {
+                formatter.write_str(if self.get((row, column)) { "#" } else { "." })?;
+            }
+            writeln!(formatter)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Positions counted around a cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// Above, below, left and right
+    Orthogonal,
+    /// All eight surrounding positions
+    Surrounding,
+    /// Every position at most this many rows and columns away
+    Radius(usize),
+}
+
+impl Neighbourhood {
+    /// `(row, column)` offsets of the positions, never the cell itself
+    pub fn offsets(self) -> Vec<(isize, isize)> {
+        let radius = match self {
+            Neighbourhood::Orthogonal => return vec![(-1, 0), (0, -1), (0, 1), (1, 0)],
+            Neighbourhood::Surrounding => 1,
+            Neighbourhood::Radius(radius) => radius as isize,
+        };
+
+        (-radius..=radius)
+            .flat_map(|row| (-radius..=radius).map(move |column| (row, column)))
+            .filter(|offset| *offset != (0, 0))
+            .collect()
+    }
+}
+
+/// What lies past the border of the grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edges {
+    /// Nothing, positions outside are never set
+    Bounded,
+    /// The opposite side, as on a torus
+    Wrapping,
+}
+
+/// Counts neighbours of every position a whole chunk at once with bit sliced adders, keeping its buffers
+/// between counts so repeated counts on one grid only allocate the result
+///
+/// The eight surrounding positions without wrapping are added from the rows above and below and their
+/// copies shifted by one column. Other neighbourhoods add copies of the grid shifted by every column offset.
+pub struct NeighbourCounter {
+    width: usize,
+    height: usize,
+    edges: Edges,
+    offsets: Vec<(isize, isize)>,
+    surrounding: bool,
+    /// Copies shifted by every column offset from `-reach` to `reach`, empty when counting the surrounding positions
+    shifted: Vec<BitGrid>,
+    counter: Counter,
+    empty: Vec<u64x4>,
+}
+
+impl NeighbourCounter {
+    /// Counter for grids of the same size as `grid`
+    pub fn new(grid: &BitGrid, neighbourhood: Neighbourhood, edges: Edges) -> Self {
+        let offsets = neighbourhood.offsets();
+        let surrounding = edges == Edges::Bounded && offsets == Neighbourhood::Surrounding.offsets();
+        let reach = offsets.iter().map(|(_, column)| column.unsigned_abs()).max().unwrap_or_default();
+        let shifted = match surrounding {
+            true => Vec::new(),
+            false => vec![BitGrid::new(grid.width, grid.height); 2 * reach + 1],
+        };
+
+        Self {
+            width: grid.width,
+            height: grid.height,
+            edges,
+            counter: Counter::new(offsets.len(), grid.chunks),
+            offsets,
+            surrounding,
+            shifted,
+            empty: vec![u64x4::splat(0); grid.chunks],
+        }
+    }
+
+    /// Set positions of `grid` with fewer than `limit` set positions in their neighbourhood
+    pub fn with_fewer(&mut self, grid: &BitGrid, limit: usize) -> BitGrid {
+        assert_eq!((self.width, self.height), (grid.width, grid.height), "grids differ in size");
+        if self.surrounding {
+            return self.with_fewer_surrounding(grid, limit);
+        }
+
+        let reach = (self.shifted.len() / 2) as isize;
+        for (offset, shifted) in (-reach..).zip(&mut self.shifted) {
+            grid.shift_columns_into(offset, self.edges, shifted);
+        }
+
+        let mut result = BitGrid::new(grid.width, grid.height);
+        for row in 0..grid.height {
+            self.counter.clear();
+            for (row_offset, column_offset) in &self.offsets {
+                let source = &self.shifted[(column_offset + reach) as usize];
+                let line = match (row.checked_add_signed(*row_offset), self.edges) {
+                    (Some(neighbour), _) if neighbour < grid.height => source.row(neighbour),
+                    (_, Edges::Wrapping) => source.row((row as isize + row_offset).rem_euclid(grid.height as isize) as usize),
+                    (_, Edges::Bounded) => &self.empty,
+                };
+                self.counter.add(line);
+            }
+
+            for (chunk, cell) in grid.row(row).iter().enumerate() {
+                result.cells[row * grid.chunks + chunk] = cell & self.counter.less_than(limit, chunk);
+            }
+        }
+
+        result
+    }
+
+    /// Shifted AND of the rows above, below and the row itself, moved by one column where needed
+    fn with_fewer_surrounding(&self, grid: &BitGrid, limit: usize) -> BitGrid {
+        let mut result = BitGrid::new(grid.width, grid.height);
+
+        for row in 0..grid.height {
+            let above = if row > 0 { grid.row(row - 1) } else { &self.empty };
+            let below = if row + 1 < grid.height { grid.row(row + 1) } else { &self.empty };
+            let current = grid.row(row);
+
+            for chunk in 0..grid.chunks {
+                let mut counter = ChunkCounter::default();
+                for line in [above, current, below] {
+                    let (from_left, from_right) = shifted(line, chunk);
+                    counter.add(from_left);
+                    counter.add(from_right);
+                }
+                counter.add(above[chunk]);
+                counter.add(below[chunk]);
+
+                result.cells[row * grid.chunks + chunk] = current[chunk] & counter.less_than(limit);
+            }
+        }
+
+        result
+    }
+}
+
+/// Chunk of a row moved by one column in both directions, so every bit holds its left and right neighbour
+fn shifted(line: &[u64x4], chunk: usize) -> (u64x4, u64x4) {
+    let zero = u64x4::splat(0);
+    let current = line[chunk];
+    let previous = if chunk > 0 { line[chunk - 1] } else { zero };
+    let next = line.get(chunk + 1).copied().unwrap_or(zero);
+
+    let carries = simd_swizzle!(previous >> 63, current >> 63, [3, 4, 5, 6]);
+    let borrows = simd_swizzle!(current << 63, next << 63, [1, 2, 3, 4]);
+
+    ((current << 1) | carries, (current >> 1) | borrows)
+}
+
+/// ORs the words of `source` moved by `offset` bits into `target`, bit `b` of the result comes from bit `b + offset`
+fn shift_words(source: &[u64x4], offset: isize, target: &mut [u64x4]) {
+    let words = source.len() * 4;
+    let word = |index: isize| usize::try_from(index).ok().filter(|index| *index < words).map_or(0, |index| source[index / 4][index % 4]);
+    for index in 0..words {
+        let start = index as isize * 64 + offset;
+        let (quotient, remainder) = (start.div_euclid(64), start.rem_euclid(64));
+        target[index / 4][index % 4] |= match remainder {
+            0 => word(quotient),
+            _ => word(quotient) >> remainder | word(quotient + 1) << (64 - remainder),
+        };
+    }
+}
+
+/// Four bit planes holding a count of up to 15 for every bit position of a chunk
+#[derive(Default)]
+struct ChunkCounter {
+    planes: [u64x4; 4],
+}
+
+impl ChunkCounter {
+    fn add(&mut self, input: u64x4) {
+        let mut carry = input;
+        for plane in &mut self.planes {
+            let sum = *plane ^ carry;
+            carry &= *plane;
+            *plane = sum;
+        }
+    }
+
+    fn less_than(&self, limit: usize) -> u64x4 {
+        (0..limit.min(16)).fold(u64x4::splat(0), |result, count| {
+            let equal = self.planes.iter().enumerate().fold(u64x4::splat(u64::MAX), |equal, (bit, plane)| {
+                equal & if count >> bit & 1 == 1 { *plane } else { !*plane }
+            });
+            result | equal
+        })
+    }
+}
+
+/// Bit planes holding a count for every bit position of a row
+struct Counter {
+    maximum: usize,
+    planes: Vec<Vec<u64x4>>,
+}
+
+impl Counter {
+    fn new(maximum: usize, chunks: usize) -> Self {
+        let bits = (usize::BITS - maximum.leading_zeros()) as usize;
+        Self { maximum, planes: vec![vec![u64x4::splat(0); chunks]; bits] }
+    }
+
+    fn clear(&mut self) {
+        for plane in &mut self.planes {
+            plane.fill(u64x4::splat(0));
+        }
+    }
+
+    fn add(&mut self, line: &[u64x4]) {
+        for (chunk, input) in line.iter().enumerate() {
+            let mut carry = *input;
+            for plane in &mut self.planes {
+                let sum = plane[chunk] ^ carry;
+                carry &= plane[chunk];
+                plane[chunk] = sum;
+            }
+        }
+    }
+
+    /// Compares the planes of one chunk with `limit` from the most significant bit down
+    fn less_than(&self, limit: usize, chunk: usize) -> u64x4 {
+        if limit > self.maximum {
+            return u64x4::splat(u64::MAX);
+        }
+        let (mut less, mut equal) = (u64x4::splat(0), u64x4::splat(u64::MAX));
+        for (bit, plane) in self.planes.iter().enumerate().rev() {
+            if limit >> bit & 1 == 1 {
+                less |= equal & !plane[chunk];
+                equal &= plane[chunk];
+            } else {
+                equal &= !plane[chunk];
+            }
+        }
+        less
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{lines, xorshift};
+    use super::*;
+
+    #[test]
+    fn sets_and_counts_bits() {
+        let mut grid = BitGrid::new(300, 2);
+        grid.set((0, 0), true);
+        grid.set((1, 63), true);
+        grid.set((1, 64), true);
+        grid.set((1, 299), true);
+        grid.set((1, 64), false);
+
+        assert_eq!(grid.count_ones(), 3);
+        assert!(grid.get((1, 299)));
+        assert!(!grid.get((1, 300)));
+        assert_eq!(grid.positions().collect::<Vec<_>>(), [(0, 0), (1, 63), (1, 299)]);
+    }
+
+    #[test]
+    fn parses_and_displays_grid() {
+        let grid = BitGrid::parse(lines(b"..@\n@@."), b'@', b'.').unwrap();
+
+        assert_eq!(grid.to_string(), "..#\n##.\n");
+        assert_eq!(
+            BitGrid::parse(lines(b"..@\n@#."), b'@', b'.').unwrap_err().to_string(),
+            "unexpected cell '#' at line 2, column 2"
+        );
+        assert_eq!(
+            BitGrid::parse(lines(b"..@\n@@....."), b'@', b'.').unwrap_err().to_string(),
+            "row is 7 cells wide, expected 3 at line 2"
+        );
+
+        let row = "@.".repeat(150);
+        let wide = BitGrid::parse(lines(format!("{row}\n{}x.", &row[..298]).as_bytes()), b'@', b'.');
+        assert_eq!(wide.unwrap_err().to_string(), "unexpected cell 'x' at line 2, column 299");
+        let wide = BitGrid::parse(lines(format!("{row}\n{row}").as_bytes()), b'@', b'.').unwrap();
+        assert_eq!((wide.width(), wide.height(), wide.count_ones()), (300, 2, 300));
+        assert!(wide.get((1, 298)) && !wide.get((1, 299)));
+    }
+
+    #[test]
+    fn counts_neighbours_across_words_and_chunks() {
+        // Wider than one chunk, so the shifts carry between words and chunks
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut grid = Grid::new(300, 7, false);
+        for position in grid.positions() {
+            grid[position] = !xorshift(&mut state).is_multiple_of(3);
+        }
+        let bits = BitGrid::from(&grid);
+
+        for limit in 0..=9 {
+            let expected = grid.positions()
+                .filter(|position| grid[*position] && grid.neighbours8(*position).filter(|neighbour| grid[*neighbour]).count() < limit)
+                .collect::<Vec<_>>();
+
+            assert_eq!(bits.with_fewer_neighbours(limit, Neighbourhood::Surrounding, Edges::Bounded).positions().collect::<Vec<_>>(), expected, "limit {limit}");
+        }
+    }
+
+    #[test]
+    fn counts_configurable_neighbourhoods() {
+        // Every neighbourhood with both edge modes, limits around and past the neighbourhood size
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        let mut grid = BitGrid::new(70, 9);
+        for row in 0..9 {
+            for column in 0..70 {
+                grid.set((row, column), xorshift(&mut state).is_multiple_of(2));
+            }
+        }
+
+        for neighbourhood in [Neighbourhood::Orthogonal, Neighbourhood::Surrounding, Neighbourhood::Radius(0), Neighbourhood::Radius(2), Neighbourhood::Radius(5)] {
+            for edges in [Edges::Bounded, Edges::Wrapping] {
+                for limit in [0, 1, 3, 4, 12, 60, 200] {
+                    let expected = grid.positions()
+                        .filter(|position| grid.neighbours(*position, neighbourhood, edges).filter(|neighbour| grid.get(*neighbour)).count() < limit)
+                        .collect::<Vec<_>>();
+
+                    assert_eq!(
+                        grid.with_fewer_neighbours(limit, neighbourhood, edges).positions().collect::<Vec<_>>(),
+                        expected,
+                        "{neighbourhood:?} {edges:?} below {limit}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn reuses_counter_while_grid_changes() {
+        let mut grid = BitGrid::parse(lines(b"@@@@@\n@@.@@\n@@@@@\n@.@@@"), b'@', b'.').unwrap();
+        let rules = [(Neighbourhood::Surrounding, Edges::Bounded, 4), (Neighbourhood::Orthogonal, Edges::Wrapping, 4), (Neighbourhood::Radius(2), Edges::Bounded, 9)];
+        let mut counters = rules.map(|(neighbourhood, edges, _)| NeighbourCounter::new(&grid, neighbourhood, edges));
+
+        while grid.count_ones() > 0 {
+            for ((neighbourhood, edges, limit), counter) in rules.iter().zip(&mut counters) {
+                assert_eq!(counter.with_fewer(&grid, *limit), grid.with_fewer_neighbours(*limit, *neighbourhood, *edges));
+            }
+            let first = grid.positions().next().unwrap();
+            grid.set(first, false);
+        }
+    }
+
+    #[test]
+    fn wraps_neighbours_around_edges() {
+        let grid = BitGrid::new(3, 3);
+
+        assert_eq!(grid.neighbours((0, 0), Neighbourhood::Orthogonal, Edges::Bounded).collect::<Vec<_>>(), [(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours((0, 0), Neighbourhood::Orthogonal, Edges::Wrapping).collect::<Vec<_>>(), [(2, 0), (0, 2), (0, 1), (1, 0)]);
+        assert_eq!(Neighbourhood::Radius(2).offsets().len(), 24);
+        assert_eq!(Neighbourhood::Surrounding.offsets(), Neighbourhood::Radius(1).offsets());
+    }
+
+    #[test]
+    fn removes_positions() {
+        let mut grid = BitGrid::parse(lines(b"@@@\n@@@"), b'@', b'.').unwrap();
+        grid.remove(&BitGrid::parse(lines(b"@.@\n.@."), b'@', b'.').unwrap());
+
+        assert_eq!(grid.to_string(), ".#.\n#.#\n");
+    }
+}
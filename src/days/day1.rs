@@ -0,0 +1,109 @@
+use std::fmt::Display;
+use crate::{lines, CheckedNumberExt, Error, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input<'a> = Vec<i16>;
+
+    fn parse(input: &[u8]) -> Result<Self::Input<'_>, Error> {
+        parse_rotations(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Option<Result<impl Display, Error>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+fn parse_rotations(input: &[u8]) -> Result<Vec<i16>, Error> {
+    let mut rotations = Vec::new();
+    for (row, line) in lines(input).enumerate() {
+        let mut column = 1;
+        for slice in line.split(|&b| b == b' ') {
+            if let Some(rotation) = parse_slice(slice).map_err(|error| error.offset(row + 1, column))? {
+                rotations.push(rotation);
+            }
+            column += slice.len() + 1;
+        }
+    }
+
+    Ok(rotations)
+}
+
+fn parse_slice(slice:&[u8]) -> Result<Option<i16>, Error> {
+    match slice.first() {
+        None => Ok(None),
+        Some(b'L') => Ok(Some(-distance(slice)?)),
+        Some(b'R') => Ok(Some(distance(slice)?)),
+        Some(direction) => Err(Error::new(format!("unknown rotation direction '{}'", *direction as char))),
+    }
+}
+
+fn distance(slice: &[u8]) -> Result<i16, Error> {
+    // The direction already carries the sign, so a second one is malformed
+    if let Some(sign @ (b'-' | b'+')) = slice.get(1) {
+        return Err(Error::new(format!("unexpected '{}' in a number", sign.escape_ascii())).at_column(2));
+    }
+
+    i16::checked_from_bytes(&slice[1..]).map_err(|error| error.offset(1, 2))
+}
+
+pub fn part1(input: &[i16]) -> i16 {
+    let mut dial: i32 = 50;
+    let mut password = 0;
+
+    for delta in input {
+        dial += *delta as i32;
+        password += if dial % 100 == 0 { 1 } else { 0 };
+    }
+
+    password as i16 
+}
+
+pub fn part2(input: &[i16]) -> i16 {
+    let mut dial = 50;
+    let mut password = 0;
+
+    for delta in input {
+        if *delta >= 0 {
+            password += (dial + delta) / 100;
+        } else {
+            let reversed = (100 - dial) % 100;
+            password += (reversed - delta) / 100;
+        }
+        dial = (dial + delta).rem_euclid(100);
+    }
+
+    password
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_rotations() {
+        assert_eq!(parse_rotations(b"L68\nR48\n\nL5").unwrap(), vec![-68, 48, -5]);
+    }
+
+    #[test]
+    fn reports_unknown_direction() {
+        assert_eq!(
+            parse_rotations(b"L68\nR48 U3").unwrap_err().to_string(),
+            "unknown rotation direction 'U' at line 2, column 5"
+        );
+        assert_eq!(
+            parse_rotations(b"L68\nR4x").unwrap_err().to_string(),
+            "unexpected 'x' in a number at line 2, column 3"
+        );
+        assert_eq!(
+            parse_rotations(b"L68\nR-5").unwrap_err().to_string(),
+            "unexpected '-' in a number at line 2, column 2"
+        );
+    }
+}
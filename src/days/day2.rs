@@ -0,0 +1,247 @@
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use crate::{digits, factors, separated, Error, Solution};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input<'a> = Vec<RangeInclusive<u64>>;
+
+    fn parse(input: &[u8]) -> Result<Self::Input<'_>, Error> {
+        parse_ranges(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        Ok(input.iter().map(sum_invalid_part1).sum::<u128>())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Option<Result<impl Display, Error>> {
+        Some(Ok(input.iter().map(sum_invalid_part2).sum::<u128>()))
+    }
+}
+
+fn parse_ranges(input: &[u8]) -> Result<Vec<RangeInclusive<u64>>, Error> {
+    let mut column = 1;
+    let mut ranges = Vec::new();
+    for slice in input.split(|char| char == &b',') {
+        if !slice.trim_ascii().is_empty() {
+            ranges.push(parse_range(slice).map_err(|error| error.offset_column(column))?);
+        }
+        column += slice.len() + 1;
+    }
+
+    Ok(ranges)
+}
+
+fn parse_range(input: &[u8]) -> Result<RangeInclusive<u64>, Error> {
+    let [start, end] = separated(input, b'-')?;
+    Ok(start..=end)
+}
+
+/// Sum of the IDs in `range` made of some digits repeated exactly twice
+fn sum_invalid_part1(range: &RangeInclusive<u64>) -> u128 {
+    lengths(range)
+        .filter(|length| length % 2 == 0)
+        .map(|length| sum_repeated(range, length, length / 2))
+        .sum()
+}
+
+/// Sum of the IDs in `range` made of some digits repeated at least twice
+///
+/// IDs like `111111` repeat several pattern lengths, so the sum for every divisor of the ID length is weighted
+/// with the Möbius function, which is inclusion–exclusion over the prime factors of the length.
+fn sum_invalid_part2(range: &RangeInclusive<u64>) -> u128 {
+    let sum = lengths(range)
+        .filter(|length| *length > 1)
+        .flat_map(|length| factors(length).chain([length]).map(move |divisor| (length, divisor)))
+        .map(|(length, divisor)| -mobius(divisor) * sum_repeated(range, length, length / divisor) as i128)
+        .sum::<i128>();
+
+    sum as u128
+}
+
+/// Digit lengths of the IDs in `range`
+fn lengths(range: &RangeInclusive<u64>) -> impl Iterator<Item=u8> + use<> {
+    digits(*range.start())..=digits(*range.end())
+}
+
+/// Sum of the IDs in `range` that are `length` digits long and repeat a pattern of `pattern` digits
+///
+/// Such IDs are the pattern times `10…010…01`, so the patterns in the range form an arithmetic series.
+fn sum_repeated(range: &RangeInclusive<u64>, length: u8, pattern: u8) -> u128 {
+    let (length, pattern) = (length as u32, pattern as u32);
+    let multiplier = (10u128.pow(length) - 1) / (10u128.pow(pattern) - 1);
+    let low = (*range.start() as u128).max(10u128.pow(length - 1));
+    let high = (*range.end() as u128).min(10u128.pow(length) - 1);
+    let first = low.div_ceil(multiplier);
+    let last = high / multiplier;
+    if first > last {
+        return 0;
+    }
+
+    multiplier * (first + last) * (last - first + 1) / 2
+}
+
+fn mobius(value: u8) -> i128 {
+    let (mut rest, mut sign) = (value, 1);
+    for prime in 2..=value {
+        if rest.is_multiple_of(prime) {
+            rest /= prime;
+            if rest.is_multiple_of(prime) {
+                return 0;
+            }
+            sign = -sign;
+        }
+    }
+
+    sign
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::xorshift;
+    use super::*;
+
+    fn from_ranges(ranges: &[RangeInclusive<u64>]) -> impl Iterator<Item=u64> + '_ {
+        ranges.iter().cloned().flatten()
+    }
+
+    fn sum_invalid(ranges: &[RangeInclusive<u64>], validator: fn(u64) -> Option<u64>) -> u64 {
+        from_ranges(ranges).filter_map(validator).sum()
+    }
+
+    fn invalid_id_part1(number: u64) -> Option<u64> {
+        let digits = digits(number);
+        if digits % 2 == 1 {
+            return None;
+        }
+        let split_point = digits / 2;
+        let multiplier = 10u64.pow(split_point as u32);
+        let left = number / multiplier;
+        let right = number % multiplier;
+
+        if left != right {
+            return None;
+        }
+
+        Some(number)
+    }
+
+    fn invalid_id_part2(number: u64) -> Option<u64> {
+        let digits = digits(number);
+
+
+        if digits == 1 {
+            return None;
+        }
+
+        let same_digit_number = ((10u64.pow(digits as u32) - 1) / 9) * (number % 10);
+
+        if same_digit_number == number {
+            return Some(number);
+        }
+
+        'outer: for factor in factors(digits) {
+            let multiplier = 10u64.pow(factor as u32);
+            let pattern = number % multiplier;
+            let mut left_part = number / multiplier;
+
+            while left_part > 0 {
+                if left_part % multiplier != pattern {
+                    continue 'outer;
+                }
+                left_part /= multiplier;
+            }
+            return Some(number);
+        }
+
+        None
+    }
+
+    #[test]
+    fn parses_ranges_of_integers() {
+        assert_eq!(
+            from_ranges(&parse_ranges(b"10-15,20-28").unwrap()).collect::<Vec<_>>(),
+            vec![10, 11, 12, 13, 14, 15, 20, 21, 22, 23, 24, 25, 26, 27, 28]
+        );
+    }
+
+    #[test]
+    fn reports_malformed_range() {
+        assert_eq!(
+            parse_ranges(b"10-15,2028,\n").unwrap_err().to_string(),
+            "expected 2 numbers separated by '-' at column 11"
+        );
+        assert_eq!(
+            parse_ranges(b"10-15,20-2x8").unwrap_err().to_string(),
+            "unexpected 'x' in a number at column 11"
+        );
+    }
+
+    #[test]
+    fn tests_invalid_ids_part1() {
+        assert_eq!(invalid_id_part1(1), None);
+        assert_eq!(invalid_id_part1(10), None);
+        assert_eq!(invalid_id_part1(11), Some(11));
+        assert_eq!(invalid_id_part1(121), None);
+        assert_eq!(invalid_id_part1(111), None);
+        assert_eq!(invalid_id_part1(1212), Some(1212));
+    }
+
+    #[test]
+    fn tests_invalid_ids_part2() {
+        assert_eq!(invalid_id_part2(1), None);
+        assert_eq!(invalid_id_part2(10), None);
+        assert_eq!(invalid_id_part2(11), Some(11));
+        assert_eq!(invalid_id_part2(121), None);
+        assert_eq!(invalid_id_part2(111), Some(111));
+        assert_eq!(invalid_id_part2(1212), Some(1212));
+        assert_eq!(invalid_id_part2(121121121), Some(121121121));
+    }
+
+    #[test]
+    fn part1_test_case() {
+        let ranges = parse_ranges(b"11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124").unwrap();
+
+        assert_eq!(sum_invalid(&ranges, invalid_id_part1), 1227775554);
+        assert_eq!(ranges.iter().map(sum_invalid_part1).sum::<u128>(), 1227775554);
+    }
+
+    #[test]
+    fn part2_test_case() {
+        let ranges = parse_ranges(b"11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124").unwrap();
+
+        assert_eq!(sum_invalid(&ranges, invalid_id_part2), 4174379265);
+        assert_eq!(ranges.iter().map(sum_invalid_part2).sum::<u128>(), 4174379265);
+    }
+
+    #[test]
+    fn sums_like_checking_every_id() {
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        for _ in 0..300 {
+            let state = xorshift(&mut seed);
+            let start = state % 10u64.pow(1 + (state >> 40) as u32 % 7);
+            let range = start..=start + (state >> 20) % 5000;
+
+            assert_eq!(sum_invalid_part1(&range), sum_invalid(std::slice::from_ref(&range), invalid_id_part1) as u128, "{range:?}");
+            assert_eq!(sum_invalid_part2(&range), sum_invalid(std::slice::from_ref(&range), invalid_id_part2) as u128, "{range:?}");
+        }
+    }
+
+    #[test]
+    fn sums_ranges_too_wide_to_enumerate() {
+        // Every doubled pattern of one to nine digits, all IDs up to 18 digits long
+        let doubled = (1..=9u32).map(|digits| {
+            let (first, last) = (10u128.pow(digits - 1), 10u128.pow(digits) - 1);
+            (10u128.pow(digits) + 1) * (first + last) * (last - first + 1) / 2
+        }).sum::<u128>();
+
+        assert_eq!(sum_invalid_part1(&(0..=10u64.pow(18) - 1)), doubled);
+        assert!(sum_invalid_part2(&(0..=u64::MAX)) > doubled);
+        assert_eq!(sum_invalid_part2(&(1..=99)), 495);
+        assert_eq!(sum_invalid_part2(&(1..=999)), 495 + 111 * 45);
+        assert_eq!(sum_invalid_part2(&(1000..=9999)), 101 * (10 + 99) * 90 / 2);
+        assert_eq!(sum_invalid_part2(&(100000..=999999)), 10101 * (10 + 99) * 90 / 2 + 1001 * (100 + 999) * 900 / 2 - 111111 * 45);
+    }
+}
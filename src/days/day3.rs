@@ -1,12 +1,39 @@
-#![feature(portable_simd)]
+use std::fmt::Display;
+use crate::{lines, Error, NumberExt, Solution};
 
-use aoc2025::{measure, NumberExt, Task};
+pub struct Day3;
 
-fn main() {
-    let input = include_bytes!("../../inputs/day3.txt");
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input<'a> = Vec<&'a [u8]>;
 
-    measure(Task::Part1, || part1(input.split(|c| *c == b'\n').filter(|v| v.len() == 100)));
-    measure(Task::Part1, || part2(input.split(|c| *c == b'\n').filter(|v| v.len() == 100)));
+    fn parse(input: &[u8]) -> Result<Self::Input<'_>, Error> {
+        parse_banks(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        Ok(part1(input.iter().copied()))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Option<Result<impl Display, Error>> {
+        Some(Ok(part2(input.iter().copied())))
+    }
+}
+
+fn parse_banks(input: &[u8]) -> Result<Vec<&[u8]>, Error> {
+    lines(input).strip_cr()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(row, line)| {
+            if let Some(column) = line.iter().position(|c| !c.is_ascii_digit()) {
+                return Err(Error::new(format!("unexpected battery '{}'", line[column] as char)).at(row + 1, column + 1));
+            }
+            if line.len() != 100 {
+                return Err(Error::new(format!("expected 100 batteries in a bank, found {}", line.len())).at_line(row + 1));
+            }
+            Ok(line)
+        })
+        .collect()
 }
 
 fn part1<'a>(input: impl Iterator<Item=&'a[u8]>) -> u16 {
@@ -68,6 +95,22 @@ pub fn battery_power_part2<const T: usize>(bytes: &[u8; T]) -> u64 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn reports_malformed_banks() {
+        let mut input = [b'1'; 202];
+        input[100] = b'\n';
+        input[151] = b'x';
+
+        assert_eq!(parse_banks(&input[..101]).unwrap().len(), 1);
+        assert_eq!(parse_banks(&input).unwrap_err().to_string(), "unexpected battery 'x' at line 2, column 51");
+        assert_eq!(parse_banks(b"12345").unwrap_err().to_string(), "expected 100 batteries in a bank, found 5 at line 1");
+
+        let mut windows = [b'1'; 204];
+        windows[100..102].copy_from_slice(b"\r\n");
+        windows[202..].copy_from_slice(b"\r\n");
+        assert_eq!(parse_banks(&windows).unwrap().len(), 2);
+    }
+
     #[test]
     fn find_max_battery_power_part1() {
         assert_eq!(battery_power_part1(b"987654321111111"), 98);
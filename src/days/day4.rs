@@ -0,0 +1,439 @@
+use std::collections::VecDeque;
+use std::fmt::Display;
+use crate::{lines, BitGrid, Edges, Error, NeighbourCounter, Neighbourhood, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input<'a> = BitGrid;
+
+    fn parse(input: &[u8]) -> Result<Self::Input<'_>, Error> {
+        parse_grid(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        Ok(part1_available_rolls(input, Rule::PUZZLE))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Option<Result<impl Display, Error>> {
+        let mut grid = input.clone();
+        Some(Ok(part2_available_rolls(&mut grid, Rule::PUZZLE)))
+    }
+}
+
+/// When a forklift can reach a roll, so variants of the puzzle can run on the same engine
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    /// Accessible with fewer rolls than this in the neighbourhood
+    pub below: usize,
+    pub neighbourhood: Neighbourhood,
+    pub edges: Edges,
+}
+
+impl Rule {
+    /// Fewer than four rolls in the eight surrounding positions, nothing past the border
+    pub const PUZZLE: Rule = Rule { below: 4, neighbourhood: Neighbourhood::Surrounding, edges: Edges::Bounded };
+
+    pub fn accessible(&self, grid: &BitGrid) -> BitGrid {
+        grid.with_fewer_neighbours(self.below, self.neighbourhood, self.edges)
+    }
+
+    /// Counter to find accessible rolls again and again on grids of the size of `grid`
+    pub fn counter(&self, grid: &BitGrid) -> NeighbourCounter {
+        NeighbourCounter::new(grid, self.neighbourhood, self.edges)
+    }
+
+    fn is_accessible(&self, grid: &BitGrid, position: (usize, usize)) -> bool {
+        grid.neighbours(position, self.neighbourhood, self.edges).filter(|neighbour| grid.get(*neighbour)).count() < self.below
+    }
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Rule::PUZZLE
+    }
+}
+
+fn parse_grid(input: &[u8]) -> Result<BitGrid, Error> {
+    BitGrid::parse(lines(input).strip_cr().skip_blank(), b'@', b'.')
+}
+
+fn part1_available_rolls(grid: &BitGrid, rule: Rule) -> u64 {
+    rule.accessible(grid).count_ones() as u64
+}
+
+/// Removes every accessible roll at once until none is left, removal only frees up neighbours
+/// so the rolls left in the end do not depend on the order
+fn part2_available_rolls(grid: &mut BitGrid, rule: Rule) -> u64 {
+    remove_in_waves(grid, rule, |_| {})
+}
+
+/// Rolls taken away together in one pass of part 2
+pub struct Wave<'a> {
+    pub number: usize,
+    /// Rolls present before the wave
+    pub grid: &'a BitGrid,
+    pub removed: &'a BitGrid,
+}
+
+impl Wave<'_> {
+    pub fn count(&self) -> usize {
+        self.removed.count_ones()
+    }
+
+    /// Binary PPM with one pixel per cell, removed rolls in red
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut image = format!("P6\n{} {}\n255\n", self.grid.width(), self.grid.height()).into_bytes();
+        for row in 0..self.grid.height() {
+            for column in 0..self.grid.width() {
+                let position = (row, column);
+                image.extend_from_slice(match (self.grid.get(position), self.removed.get(position)) {
+                    (true, true) => &[220, 50, 50],
+                    (true, false) => &[200, 200, 200],
+                    _ => &[32, 32, 32],
+                });
+            }
+        }
+
+        image
+    }
+}
+
+/// Header with the wave number and count, followed by the grid where `x` marks the removed rolls
+impl Display for Wave<'_> {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        writeln!(formatter, "wave {}: removed {} rolls", self.number, self.count())?;
+        formatter.write_str(&render(self.grid, self.removed))
+    }
+}
+
+/// Part 2 that reports every wave before its rolls are removed, returns the total removed
+pub fn remove_in_waves(grid: &mut BitGrid, rule: Rule, mut on_wave: impl FnMut(&Wave)) -> u64 {
+    let mut counter = rule.counter(grid);
+    let mut total = 0;
+    for number in 1.. {
+        let accessible = counter.with_fewer(grid, rule.below);
+        let removed = accessible.count_ones();
+        if removed == 0 {
+            break;
+        }
+
+        on_wave(&Wave { number, grid, removed: &accessible });
+        total += removed as u64;
+        grid.remove(&accessible);
+    }
+
+    total
+}
+
+/// Rolls removed by each wave of part 2 and the grid left once nothing else is accessible
+#[derive(Debug, Clone, PartialEq)]
+pub struct Removal {
+    pub per_wave: Vec<usize>,
+    pub stable: BitGrid,
+}
+
+impl Removal {
+    /// Waves it takes until the grid is stable
+    pub fn waves(&self) -> usize {
+        self.per_wave.len()
+    }
+
+    pub fn total(&self) -> u64 {
+        self.per_wave.iter().sum::<usize>() as u64
+    }
+}
+
+/// Removal history where every wave is a generation, all rolls accessible at its start go at once
+pub fn removal_history(grid: &BitGrid, rule: Rule) -> Removal {
+    let mut stable = grid.clone();
+    let mut per_wave = Vec::new();
+    remove_in_waves(&mut stable, rule, |wave| per_wave.push(wave.count()));
+
+    Removal { per_wave, stable }
+}
+
+/// Removal history of [`remove_queued`], later checks in a wave already see the rolls taken away before them
+pub fn removal_history_queued(grid: &BitGrid, rule: Rule) -> Removal {
+    let mut stable = grid.clone();
+    let mut per_wave = Vec::new();
+    remove_queued(&mut stable, rule, |_, wave| {
+        if per_wave.len() == wave {
+            per_wave.push(0);
+        }
+        per_wave[wave] += 1;
+    });
+
+    Removal { per_wave, stable }
+}
+
+/// Part 2 as a work queue of single cells, the reference for the waves counted on whole rows. A roll is taken
+/// away as soon as it is checked and found accessible, then its remaining neighbours are queued to be checked again.
+///
+/// `on_removal` gets every removed roll with its wave, 0 for the first check of every roll and one more for
+/// every requeue. Returns the total removed.
+pub fn remove_queued(grid: &mut BitGrid, rule: Rule, mut on_removal: impl FnMut((usize, usize), usize)) -> u64 {
+    let mut cells = grid.positions().map(|(row, column)| (Cell(row, column), 0)).collect::<VecDeque<_>>();
+    let mut total = 0;
+
+    while let Some((cell, wave)) = cells.pop_front() {
+        if cell.is_roll(grid) && rule.is_accessible(grid, cell.position()) {
+            total += 1;
+            cell.remove(grid);
+            on_removal(cell.position(), wave);
+            cell.fill_non_empty_neighbours(grid, rule, wave + 1, &mut cells);
+        }
+    }
+
+    total
+}
+
+/// Like [`remove_in_waves`], but reports the waves of [`remove_queued`], where a roll can go in the same wave
+/// as the neighbour that freed it up
+pub fn remove_in_queue_waves(grid: &mut BitGrid, rule: Rule, mut on_wave: impl FnMut(&Wave)) -> u64 {
+    let mut waves: Vec<BitGrid> = Vec::new();
+    let total = remove_queued(&mut grid.clone(), rule, |position, wave| {
+        if waves.len() == wave {
+            waves.push(BitGrid::new(grid.width(), grid.height()));
+        }
+        waves[wave].set(position, true);
+    });
+
+    for (number, removed) in (1..).zip(&waves) {
+        on_wave(&Wave { number, grid, removed });
+        grid.remove(removed);
+    }
+
+    total
+}
+
+/// Position waiting in the work queue of [`remove_queued`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cell(usize, usize);
+
+impl Cell {
+    fn position(self) -> (usize, usize) {
+        (self.0, self.1)
+    }
+
+    fn is_roll(self, grid: &BitGrid) -> bool {
+        grid.get(self.position())
+    }
+
+    fn remove(self, grid: &mut BitGrid) {
+        grid.set(self.position(), false);
+    }
+
+    fn fill_non_empty_neighbours(self, grid: &BitGrid, rule: Rule, wave: usize, cells: &mut VecDeque<(Cell, usize)>) {
+        cells.extend(grid.neighbours(self.position(), rule.neighbourhood, rule.edges)
+            .filter(|neighbour| grid.get(*neighbour))
+            .map(|(row, column)| (Cell(row, column), wave)));
+    }
+}
+
+pub fn display_grid(grid: &BitGrid, rule: Rule) -> String {
+    render(grid, &rule.accessible(grid))
+}
+
+fn render(grid: &BitGrid, marked: &BitGrid) -> String {
+    (0..grid.height()).flat_map(|row| {
+        (0..grid.width()).map(move |column| match (grid.get((row, column)), marked.get((row, column))) {
+            (true, true) => 'x',
+            (true, false) => '@',
+            _ => '.',
+        }).chain(['\n'])
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::xorshift;
+    use super::*;
+
+    #[test]
+    fn parses_grid() {
+        let grid = parse_grid(b"...@.\n..@..\n.....\n").unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (5, 3));
+        assert_eq!(grid.positions().collect::<Vec<_>>(), [(0, 3), (1, 2)]);
+        assert_eq!(
+            parse_grid(b"...@.\r\n..@..\r\n.....\r\n").unwrap(),
+            grid
+        );
+        assert_eq!(parse_grid(b"...@.\n..@..\n.....\n\n").unwrap(), grid);
+    }
+
+    #[test]
+    fn reports_malformed_grid() {
+        assert_eq!(
+            parse_grid(b"...@.\n..#..\n").unwrap_err().to_string(),
+            "unexpected cell '#' at line 2, column 3"
+        );
+        assert_eq!(
+            parse_grid(b"...@.\n..@......\n").unwrap_err().to_string(),
+            "row is 9 cells wide, expected 5 at line 2"
+        );
+    }
+
+    #[test]
+    fn counts_rolls_in_grid_wider_than_256_columns() {
+        // Two rows of 300 rolls, only the four corners have fewer than four neighbours
+        let row = "@".repeat(300);
+        let mut grid = parse_grid(format!("{row}\n{row}\n").as_bytes()).unwrap();
+
+        assert_eq!(part1_available_rolls(&grid, Rule::PUZZLE), 4);
+        assert_eq!(part2_available_rolls(&mut grid, Rule::PUZZLE), 600);
+    }
+
+    #[test]
+    fn part1_count_rolls() {
+        let grid = parse_grid(
+    b"..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+").unwrap();
+
+        assert_eq!(display_grid(&grid, Rule::PUZZLE), "..xx.xx@x.
+x@@.@.@.@@
+@@@@@.x.@@
+@.@@@@..@.
+x@.@@@@.@x
+.@@@@@@@.@
+.@.@.@.@@@
+x.@@@.@@@@
+.@@@@@@@@.
+x.x.@@@.x.
+");
+
+        assert_eq!(part1_available_rolls(&grid, Rule::PUZZLE), 13);
+    }
+
+    #[test]
+    fn part2_count_rolls_after_move() {
+        let mut grid = parse_grid(
+            b"..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+").unwrap();
+
+        assert_eq!(part2_available_rolls(&mut grid, Rule::PUZZLE), 43);
+    }
+
+    #[test]
+    fn applies_configured_rule() {
+        let grid = parse_grid(b"@@@\n@@@\n@@@\n").unwrap();
+        let orthogonal = Rule { below: 3, neighbourhood: Neighbourhood::Orthogonal, ..Rule::PUZZLE };
+        let toroidal = Rule { edges: Edges::Wrapping, ..Rule::PUZZLE };
+
+        assert_eq!(display_grid(&grid, Rule::PUZZLE), "x@x\n@@@\nx@x\n");
+        assert_eq!(display_grid(&grid, orthogonal), "x@x\n@@@\nx@x\n");
+        assert_eq!(display_grid(&grid, Rule { below: 4, ..orthogonal }), "xxx\nx@x\nxxx\n");
+        assert_eq!(part1_available_rolls(&grid, toroidal), 0);
+        assert_eq!(part1_available_rolls(&grid, Rule { below: 9, ..toroidal }), 9);
+        assert_eq!(removal_history(&grid, orthogonal).per_wave, [4, 4, 1]);
+        assert_eq!(removal_history_queued(&grid, orthogonal).per_wave, [9]);
+    }
+
+    #[test]
+    fn compares_removal_histories() {
+        let grid = parse_grid(
+            b"..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+").unwrap();
+
+        let synchronous = removal_history(&grid, Rule::PUZZLE);
+        let queued = removal_history_queued(&grid, Rule::PUZZLE);
+
+        assert_eq!(synchronous.per_wave, [13, 12, 7, 5, 2, 1, 1, 1, 1]);
+        assert_eq!(synchronous.waves(), 9);
+        assert_eq!(synchronous.total(), 43);
+        assert_eq!(queued.total(), 43);
+        assert!(queued.waves() <= synchronous.waves());
+        assert_eq!(queued.stable, synchronous.stable);
+        assert_eq!(synchronous.stable.count_ones(), grid.count_ones() - 43);
+    }
+
+    #[test]
+    fn queue_matches_waves() {
+        // Removing in waves has to end where the work queue ends
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let rules = [
+            Rule::PUZZLE,
+            Rule { below: 3, neighbourhood: Neighbourhood::Orthogonal, edges: Edges::Wrapping },
+            Rule { below: 10, neighbourhood: Neighbourhood::Radius(2), ..Rule::PUZZLE },
+        ];
+
+        for width in [10, 70, 300] {
+            let mut grid = BitGrid::new(width, 12);
+            for row in 0..12 {
+                for column in 0..width {
+                    grid.set((row, column), !xorshift(&mut state).is_multiple_of(4));
+                }
+            }
+
+            for rule in rules {
+                let (mut waves, mut queued) = (grid.clone(), grid.clone());
+                let mut removed = Vec::new();
+
+                assert_eq!(remove_in_waves(&mut waves, rule, |_| {}), remove_queued(&mut queued, rule, |position, _| removed.push(position)), "{rule:?}");
+                assert_eq!(waves, queued, "{rule:?}");
+                assert!(removed.iter().all(|position| grid.get(*position) && !queued.get(*position)));
+            }
+        }
+    }
+
+    #[test]
+    fn reports_each_removal_wave() {
+        let mut grid = parse_grid(b"@@@@\n@@@@\n@@@@\n").unwrap();
+        let mut frames = Vec::new();
+
+        let total = remove_in_waves(&mut grid, Rule::PUZZLE, |wave| frames.push((wave.to_string(), wave.to_ppm().len())));
+
+        assert_eq!(total, 12);
+        assert_eq!(
+            frames.iter().map(|(frame, _)| frame.as_str()).collect::<Vec<_>>(),
+            [
+                "wave 1: removed 4 rolls\nx@@x\n@@@@\nx@@x\n",
+                "wave 2: removed 2 rolls\n.@@.\nx@@x\n.@@.\n",
+                "wave 3: removed 4 rolls\n.xx.\n.@@.\n.xx.\n",
+                "wave 4: removed 2 rolls\n....\n.xx.\n....\n",
+            ]
+        );
+        assert_eq!(frames[0].1, "P6\n4 3\n255\n".len() + 4 * 3 * 3);
+        assert_eq!(grid.count_ones(), 0);
+    }
+
+    #[test]
+    fn reports_each_queued_wave() {
+        let mut grid = parse_grid(b"@@@\n@@@\n@@@\n").unwrap();
+        let orthogonal = Rule { below: 3, neighbourhood: Neighbourhood::Orthogonal, ..Rule::PUZZLE };
+        let mut frames = Vec::new();
+
+        assert_eq!(remove_in_queue_waves(&mut grid, orthogonal, |wave| frames.push(wave.to_string())), 9);
+        assert_eq!(frames, ["wave 1: removed 9 rolls\nxxx\nxxx\nxxx\n"]);
+        assert_eq!(grid.count_ones(), 0);
+    }
+}
@@ -0,0 +1,444 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::simd::prelude::*;
+use crate::{sections, separated, Error, IntervalSet, SimdNumberExt, Solution};
+
+#[derive(Debug)]
+pub struct SimdRange
+{
+    start: u64x8,
+    end: u64x8,
+    range: RangeInclusive<u64>,
+    /// Where the range is listed in the input, parsing sorts the ranges afterwards
+    position: usize,
+}
+
+
+impl SimdRange {
+    fn new(start: u64, end: u64) -> SimdRange {
+        Self {
+            start: u64x8::splat(start),
+            end: u64x8::splat(end),
+            range: start..=end,
+            position: 0,
+        }
+    }
+
+    fn at_position(self, position: usize) -> SimdRange {
+        Self { position, ..self }
+    }
+
+    /// Lanes inside of the range, both bounds are inclusive exactly like [`SimdRange::contains`]
+    fn match_numbers(&self, slice: u64x8) -> mask64x8
+    {
+        slice.simd_ge(self.start) & slice.simd_le(self.end)
+    }
+
+    pub fn contains(&self, number: u64) -> bool {
+        self.range.contains(&number)
+    }
+
+    pub fn range(&self) -> RangeInclusive<u64> {
+        self.range.clone()
+    }
+
+    /// 0-based position among the ranges of the input
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+/// Ranges are equal and ordered by their bounds alone, wherever they are listed in the input
+impl PartialEq for SimdRange {
+    fn eq(&self, other: &Self) -> bool {
+        self.range == other.range
+    }
+}
+
+impl Eq for SimdRange {}
+
+impl PartialOrd<SimdRange> for SimdRange {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SimdRange {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let (other_start, other_end) = (*other.range.start(), *other.range.end());
+        match start.cmp(&other_start) {
+            Ordering::Equal => end.cmp(&other_end),
+            ord => ord
+        }
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input<'a> = (Vec<SimdRange>, Vec<u64>);
+
+    fn parse(input: &[u8]) -> Result<Self::Input<'_>, Error> {
+        parse_input(input)
+    }
+
+    fn part1((ranges, numbers): &Self::Input<'_>) -> Result<impl Display, Error> {
+        Ok(count_fresh(ranges, numbers))
+    }
+
+    fn part2((ranges, _): &Self::Input<'_>) -> Option<Result<impl Display, Error>> {
+        Some(Ok(part2(ranges)))
+    }
+}
+
+fn parse_input(input: &[u8]) -> Result<(Vec<SimdRange>, Vec<u64>), Error>
+{
+    let mut sections = sections(input);
+    let ranges_section = sections.next().ok_or_else(|| Error::new("expected a section of fresh ingredient ranges"))?;
+    let ids_section = sections.next()
+        .ok_or_else(|| Error::new("expected a section of ingredient IDs after the ranges"))?;
+    if let Some(extra) = sections.next() {
+        return Err(Error::new("unexpected section after the ingredient IDs").at_line(extra.line));
+    }
+
+    let mut ranges = ranges_section.lines()
+        .zip(ranges_section.line..)
+        .enumerate()
+        .map(|(position, (line, row))| {
+            let [start, end] = separated(line, b'-').map_err(|error| error.offset(row, 1))?;
+            if start > end {
+                return Err(Error::new(format!("range start {start} is after its end {end}")).at_line(row));
+            }
+            Ok(SimdRange::new(start, end).at_position(position))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let ids = ids_section.lines()
+        .zip(ids_section.line..)
+        .map(|(line, row)| u64::simd_from_bytes(line).map_err(|error| error.offset(row, 1)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    ranges.sort();
+    Ok((ranges, ids))
+}
+
+/// Counts IDs inside of any range with a binary search over the merged ranges, O((ids + ranges) log ranges)
+pub fn count_fresh(ranges: &[SimdRange], numbers: &[u64]) -> usize
+{
+    let fresh = merge_ranges(ranges);
+    numbers.iter().filter(|number| fresh.contains(**number)).count()
+}
+
+/// Checks every chunk of eight IDs against every range, O(ids × ranges), kept as a reference for benchmarks
+pub fn count_fresh_scanning(ranges: &[SimdRange], numbers: &[u64]) -> usize
+{
+    let chunks = numbers.chunks_exact(8);
+    let remainder = chunks.remainder();
+
+    chunks.fold(0, |acc, chunk| {
+        acc + valid_numbers(chunk, ranges)
+    }) + valid_numbers(remainder, ranges)
+}
+
+fn part2(ranges: &[SimdRange]) -> u128
+{
+    merge_ranges(ranges).covered_len()
+}
+
+fn valid_numbers(input: &[u64], ranges: &[SimdRange]) -> usize {
+    let chunk = u64x8::load_or_default(input);
+    // Lanes past the end of a short chunk are zero and must not match a range starting at 0
+    let loaded = mask64x8::from_bitmask((1 << input.len()) - 1);
+    let valid = ranges.iter()
+        .fold(
+            mask64x8::splat(false),
+            move |acc, range| acc | range.match_numbers(chunk)
+        );
+
+    (valid & loaded).to_bitmask().count_ones() as usize
+}
+
+fn merge_ranges(ranges: &[SimdRange]) -> IntervalSet<u64>
+{
+    IntervalSet::from_ranges(ranges.iter().map(SimdRange::range))
+}
+
+/// Interval stabbing index over parsed ranges, tells which ranges contain an ID and which IDs no range covers
+pub struct RangeIndex {
+    /// Start, end and input position of every range, sorted by start
+    ranges: Vec<(u64, u64, usize)>,
+    /// Largest end among the ranges up to each position, a query stops once no earlier range reaches the ID
+    reach: Vec<u64>,
+    covered: IntervalSet<u64>,
+}
+
+impl RangeIndex {
+    pub fn new(ranges: &[SimdRange]) -> Self {
+        let mut sorted = ranges.iter()
+            .map(|range| (*range.range.start(), *range.range.end(), range.position))
+            .collect::<Vec<_>>();
+        sorted.sort_unstable();
+        let reach = sorted.iter()
+            .scan(0, |reach, (_, end, _)| {
+                *reach = (*reach).max(*end);
+                Some(*reach)
+            })
+            .collect();
+
+        Self { ranges: sorted, reach, covered: merge_ranges(ranges) }
+    }
+
+    /// Input positions of the ranges containing `id`, see [`SimdRange::position`], in ascending order
+    pub fn containing(&self, id: u64) -> Vec<usize> {
+        let candidates = self.ranges.partition_point(|(start, _, _)| *start <= id);
+        let mut found = (0..candidates).rev()
+            .take_while(|position| self.reach[*position] >= id)
+            .filter_map(|position| {
+                let (_, end, index) = self.ranges[position];
+                (end >= id).then_some(index)
+            })
+            .collect::<Vec<_>>();
+        found.sort_unstable();
+
+        found
+    }
+
+    pub fn contains(&self, id: u64) -> bool {
+        self.covered.contains(id)
+    }
+
+    /// IDs not covered by any range, in the order given
+    pub fn uncovered<'a>(&'a self, ids: &'a [u64]) -> impl Iterator<Item=u64> + 'a {
+        ids.iter().copied().filter(|id| !self.contains(*id))
+    }
+
+    /// Runs of IDs within `bounds` that no range covers
+    pub fn gaps(&self, bounds: RangeInclusive<u64>) -> IntervalSet<u64> {
+        self.covered.complement(bounds)
+    }
+
+    /// Every ID covered by at least one range
+    pub fn covered(&self) -> &IntervalSet<u64> {
+        &self.covered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::xorshift;
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let input = b"3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32";
+
+        let (ranges, numbers) = parse_input(input).unwrap();
+
+        assert_eq!(
+            ranges,
+            vec![
+                SimdRange::new(3,5),
+                SimdRange::new(10,14),
+                SimdRange::new(12,18),
+                SimdRange::new(16,20),
+            ]
+        );
+        assert_eq!(
+            numbers,
+            vec![1, 5, 8, 11, 17, 32]
+        );
+    }
+
+    #[test]
+    fn reports_reversed_range() {
+        assert_eq!(
+            parse_input(b"3-5\n20-16\n\n1").unwrap_err().to_string(),
+            "range start 20 is after its end 16 at line 2"
+        );
+        assert_eq!(
+            parse_input(b"3-5\n10-1a\n\n1").unwrap_err().to_string(),
+            "unexpected 'a' in a number at line 2, column 5"
+        );
+    }
+
+    #[test]
+    fn reports_missing_and_extra_sections() {
+        assert_eq!(
+            parse_input(b"3-5\n10-14\n").unwrap_err().to_string(),
+            "expected a section of ingredient IDs after the ranges"
+        );
+        assert_eq!(
+            parse_input(b"3-5\n\n1\n\n2").unwrap_err().to_string(),
+            "unexpected section after the ingredient IDs at line 5"
+        );
+        assert_eq!(
+            parse_input(b"3-5\n\n1\n2-4").unwrap_err().to_string(),
+            "unexpected '-' in a number at line 4, column 2"
+        );
+    }
+
+    #[test]
+    fn test_simd_range_on_slice() {
+        assert_eq!(
+            SimdRange::new(3,5)
+            .match_numbers(
+                u64x8::from_array([1, 5, 8, 11, 17, 32, 0, 0])
+            ),
+            mask64x8::from_array(
+                [false, true, false, false, false, false, false, false]
+            )
+        );
+    }
+
+    #[test]
+    fn includes_both_bounds() {
+        let range = SimdRange::new(3, 5);
+
+        assert_eq!(
+            range.match_numbers(u64x8::from_array([2, 3, 4, 5, 6, 0, u64::MAX, 3])),
+            mask64x8::from_array([false, true, true, true, false, false, false, true])
+        );
+        assert!(range.contains(3) && range.contains(5) && !range.contains(6));
+        assert_eq!(count_fresh_scanning(&[range], &[3, 5]), 2);
+    }
+
+    #[test]
+    fn matches_numbers_like_scalar_contains() {
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        for _ in 0..10_000 {
+            // Small values so that bounds are hit often, with the odd extreme value mixed in
+            let value = |state: &mut u64| match xorshift(state) % 16 {
+                0 => u64::MAX - xorshift(state) % 2,
+                _ => xorshift(state) % 64,
+            };
+            let (first, second) = (value(&mut state), value(&mut state));
+            let range = SimdRange::new(first.min(second), first.max(second));
+            let numbers = std::array::from_fn(|_| value(&mut state));
+
+            let matched = range.match_numbers(u64x8::from_array(numbers)).to_array();
+            assert_eq!(matched, numbers.map(|number| range.range().contains(&number)), "{:?} {numbers:?}", range.range());
+            assert_eq!(matched, numbers.map(|number| range.contains(number)));
+        }
+    }
+
+    #[test]
+    fn counts_fresh_like_scanning() {
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        for _ in 0..200 {
+            let count = xorshift(&mut state) % 20;
+            let mut ranges = (0..count).map(|_| {
+                let start = xorshift(&mut state) % 200;
+                SimdRange::new(start, start + xorshift(&mut state) % 20)
+            }).collect::<Vec<_>>();
+            ranges.sort();
+            let numbers = (0..xorshift(&mut state) % 50).map(|_| xorshift(&mut state) % 230).collect::<Vec<_>>();
+
+            let expected = numbers.iter().filter(|number| ranges.iter().any(|range| range.range().contains(number))).count();
+            assert_eq!(count_fresh(&ranges, &numbers), expected);
+            assert_eq!(count_fresh_scanning(&ranges, &numbers), expected);
+        }
+    }
+
+    #[test]
+    fn part1_test_case() {
+        let ranges = [
+            SimdRange::new(3,5),
+            SimdRange::new(10,14),
+            SimdRange::new(12,18),
+            SimdRange::new(16,20),
+        ];
+
+        assert_eq!(count_fresh(&ranges, &[1, 5, 8, 11, 17, 32]), 3);
+        assert_eq!(count_fresh_scanning(&ranges, &[1, 5, 8, 11, 17, 32]), 3);
+    }
+
+    #[test]
+    fn searches_many_merged_ranges() {
+        let ranges = (0..100).map(|index| SimdRange::new(index * 10, index * 10 + 4)).collect::<Vec<_>>();
+        let numbers = (1..=1003).collect::<Vec<_>>();
+
+        assert_eq!(count_fresh(&ranges, &numbers), 499);
+        assert_eq!(count_fresh_scanning(&ranges, &numbers), 499);
+    }
+
+    #[test]
+    fn queries_ranges_containing_ids() {
+        let (ranges, ids) = parse_input(b"3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32").unwrap();
+        let index = RangeIndex::new(&ranges);
+
+        assert_eq!(ranges.iter().map(SimdRange::range).collect::<Vec<_>>(), [3..=5, 10..=14, 12..=18, 16..=20]);
+        assert_eq!(ranges.iter().map(SimdRange::position).collect::<Vec<_>>(), [0, 1, 3, 2]);
+        assert_eq!(index.containing(5), [0]);
+        assert_eq!(index.containing(13), [1, 3]);
+        assert_eq!(index.containing(17), [2, 3]);
+        assert_eq!(index.containing(8), []);
+        assert_eq!(index.uncovered(&ids).collect::<Vec<_>>(), [1, 8, 32]);
+        assert_eq!(index.gaps(0..=25).ranges(), [0..=2, 6..=9, 21..=25]);
+        assert_eq!(index.covered().covered_len(), 14);
+    }
+
+    #[test]
+    fn queries_like_checking_every_range() {
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        for _ in 0..100 {
+            let ranges = (0..xorshift(&mut state) % 30).map(|position| {
+                let start = xorshift(&mut state) % 300;
+                SimdRange::new(start, start + xorshift(&mut state) % 60).at_position(position as usize)
+            }).collect::<Vec<_>>();
+            let index = RangeIndex::new(&ranges);
+
+            for id in 0..400 {
+                let expected = ranges.iter().enumerate()
+                    .filter(|(_, range)| range.contains(id))
+                    .map(|(position, _)| position)
+                    .collect::<Vec<_>>();
+                assert_eq!(index.containing(id), expected);
+                assert_eq!(index.contains(id), !expected.is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn part2_deduplicate_ranges() {
+
+        assert_eq!(
+            merge_ranges(&[
+                SimdRange::new(3,5),
+                SimdRange::new(10,14),
+                SimdRange::new(12,18),
+                SimdRange::new(16,20),
+            ]).ranges(),
+            [
+                3..=5,
+                10..=20
+            ]
+        )
+    }
+
+    #[test]
+    fn part2_test_case() {
+
+        assert_eq!(
+            part2(&[
+                SimdRange::new(3,5),
+                SimdRange::new(10,14),
+                SimdRange::new(12,18),
+                SimdRange::new(16,20),
+            ]),
+            14
+        )
+    }
+}
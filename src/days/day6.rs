@@ -0,0 +1,374 @@
+use std::fmt::Display;
+use std::ops::{Index, IndexMut, Range};
+use std::simd::prelude::*;
+use crate::{lines, Error, NumberExt, Solution};
+
+#[derive(Debug, PartialEq)]
+enum Op {
+    Multiply([u64; 16]),
+    Add([u64; 16]),
+}
+
+impl Op {
+    fn add() -> Self {
+        Self::Add([0; 16])
+    }
+
+    fn mul() -> Self {
+        Self::Multiply([1; 16])
+    }
+
+    #[cfg(test)]
+    fn with_values(mut self, slice: &[u64]) -> Self {
+        assert!(slice.len() < 16);
+        self.mut_slice()[..slice.len()].copy_from_slice(slice);
+        self
+    }
+
+    fn mut_slice(&mut self) -> &mut [u64] {
+        match self {
+            Self::Multiply(m) | Self::Add(m) => m
+        }
+    }
+
+    fn slice(&self) -> &[u64] {
+        match self {
+            Self::Multiply(m) | Self::Add(m) => m
+        }
+    }
+}
+
+impl Index<usize> for Op {
+    type Output = u64;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.slice()[index]
+    }
+}
+
+impl IndexMut<usize> for Op {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.mut_slice()[index]
+    }
+}
+
+impl Op {
+    fn process(self) -> u64
+    {
+        match self {
+            Op::Multiply(values) => values.into_iter().fold(1, u64::wrapping_mul),
+            Op::Add(values) => values.into_iter().fold(0, u64::wrapping_add)
+        }
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input<'a> = &'a [u8];
+
+    fn parse(input: &[u8]) -> Result<Self::Input<'_>, Error> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        part_one(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Option<Result<impl Display, Error>> {
+        Some(part_two(input))
+    }
+}
+
+fn part_one(input: &[u8]) -> Result<u64, Error>
+{
+    let columns = parse_part1(input)?;
+    Ok(columns.into_iter().fold(0, |acc, col| col.process() + acc))
+}
+
+fn part_two(input: &[u8]) -> Result<u64, Error>
+{
+    let columns = parse_part2(input)?;
+    Ok(columns.into_iter().fold(0, |acc, col| col.process() + acc))
+}
+
+const SPACE: u8x64 = u8x64::splat(b' ');
+const MAX_WIDTH: usize = 4096;
+
+/// Number rows padded to the same width and the operator row, which is the last line that is not blank
+fn parse_rows(input: &[u8]) -> Result<(Vec<[u8; MAX_WIDTH]>, &[u8]), Error> {
+    let mut rows = lines(input).strip_cr().counted();
+    let operators = rows.by_ref().rev().find(|line| !line.trim_ascii().is_empty())
+        .ok_or_else(|| Error::new("expected number rows followed by an operator row"))?;
+    let operator_line = rows.len() + 1;
+    if operator_line == 1 {
+        return Err(Error::new("expected number rows before the operator row").at_line(1));
+    }
+    if operator_line > 17 {
+        return Err(Error::new(format!("found {} number rows, at most 16 are supported", operator_line - 1)).at_line(17));
+    }
+
+    let mut lines = Vec::with_capacity(rows.len());
+    for (index, line) in rows.enumerate() {
+        if line.len() > MAX_WIDTH {
+            return Err(Error::new(format!("row is {} characters wide, at most {MAX_WIDTH} are supported", line.len())).at_line(index + 1));
+        }
+        let mut row = [0u8; MAX_WIDTH];
+        row[..line.len()].copy_from_slice(line);
+        lines.push(row);
+    }
+
+    Ok((lines, operators))
+}
+
+fn operator(byte: u8, range: &Range<usize>, line: usize) -> Result<Op, Error> {
+    let column = match byte {
+        b'*' => Op::mul(),
+        b'+' => Op::add(),
+        _ => return Err(Error::new(format!("unknown operator '{}'", byte as char)).at(line, range.start + 1)),
+    };
+
+    if range.len() > 16 {
+        return Err(Error::new(format!("problem is {} digits wide, at most 16 are supported", range.len())).at(line, range.start + 1));
+    }
+
+    Ok(column)
+}
+
+fn parse_part1(input: &[u8]) -> Result<Vec<Op>, Error> {
+    let (lines, input) = parse_rows(input)?;
+    let mut result = Vec::new();
+    for (byte, range) in frames(input) {
+        let mut column = operator(byte, &range, lines.len() + 1)?;
+
+        for (index, row) in lines.iter().enumerate() {
+            column[index] = u64::from_bytes(&row[range.clone()]);
+        }
+
+        result.push(column);
+    }
+
+    Ok(result)
+}
+
+fn parse_part2(input: &[u8]) -> Result<Vec<Op>, Error> {
+    let (lines, input) = parse_rows(input)?;
+    let mut result = Vec::new();
+    for (byte, range) in frames(input) {
+        let mut column = operator(byte, &range, lines.len() + 1)?;
+
+        for (index, col) in range.rev().enumerate() {
+            let number = lines.iter()
+                .map(|row| row[col])
+                .filter(|v| *v >= b'0')
+                .fold(0, |acc, v| acc * 10 + (v - b'0') as u64);
+
+            column[index] = number;
+        }
+
+        result.push(column);
+    }
+
+    Ok(result)
+}
+
+
+fn frames(mut line: &[u8]) -> Vec<(u8, Range<usize>)> {
+    let mut position = 0;
+    let mut frames = Vec::<(u8, Range<usize>)>::with_capacity(line.len() / 3);
+    while !line.is_empty() {
+        let scan = u8x64::load_or(line, SPACE);
+        let mut spaces = scan.simd_eq(SPACE).to_bitmask();
+
+        while spaces.trailing_zeros() < 64 {
+            let bytes_to_read = spaces.trailing_zeros() as usize;
+            spaces >>= spaces.trailing_zeros();
+            let bytes_to_skip = (bytes_to_read + spaces.trailing_ones() as usize).min(line.len());
+            let last_byte = if line.len() - bytes_to_skip > 0 {
+                (bytes_to_read + spaces.trailing_ones() as usize - 1).min(line.len())
+            } else {
+                bytes_to_read + line.len() - 1
+            };
+
+            spaces >>= spaces.trailing_ones();
+
+            if spaces.trailing_zeros() == 64 && line.len() != bytes_to_skip {
+                break;
+            }
+
+            let char = line[0];
+            frames.push((
+                char, position..position + last_byte,
+            ));
+            position += bytes_to_skip;
+            line = &line[bytes_to_skip..];
+        }
+    }
+
+    frames
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::line;
+    use super::*;
+
+    #[test]
+    fn parsing_columns_part1() {
+        let input = b"123 3289  51 64
+ 45 64   387 23
+  6 98   215 314
+*   +    *   +  ";
+
+        assert_eq!(
+            parse_part1(input).unwrap(),
+            vec![
+                Op::mul().with_values(&[123, 45, 6]),
+                Op::add().with_values(&[3289, 64, 98]),
+                Op::mul().with_values(&[51, 387, 215]),
+                Op::add().with_values(&[64, 23, 314]),
+            ],
+        );
+    }
+
+    #[test]
+    fn parsing_columns_part2() {
+        let input = b"123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  \n";
+
+        assert_eq!(
+            parse_part2(input).unwrap(),
+            vec![
+                Op::mul().with_values(&[356, 24, 1]),
+                Op::add().with_values(&[8, 248, 369]),
+                Op::mul().with_values(&[175, 581, 32]),
+                Op::add().with_values(&[4, 431, 623]),
+            ],
+        );
+    }
+
+    #[test]
+    fn parsing_columns_part2_with_longer_tail() {
+        let input = b"123 328  51 64  \n 45 64  387 23  \n  6 98  215 3145\n*   +   *   +   \n";
+
+        assert_eq!(
+            parse_part2(input).unwrap(),
+            vec![
+                Op::mul().with_values(&[356, 24, 1]),
+                Op::add().with_values(&[8, 248, 369]),
+                Op::mul().with_values(&[175, 581, 32]),
+                Op::add().with_values(&[5, 4, 431, 623]),
+            ],
+        );
+    }
+
+
+    #[test]
+    fn part_one_test_case() {
+        let input = b"123 328  51 64
+ 45 64  387 23
+  6 98  215 314
+*   +   *   +  ";
+
+        assert_eq!(part_one(input).unwrap(), 4277556);
+    }
+
+
+    #[test]
+    fn part_two_test_case() {
+        let input = b"123 328  51 64
+ 45 64  387 23
+  6 98  215 314
+*   +   *   +  ";
+
+        assert_eq!(part_two(input).unwrap(), 3263827);
+    }
+
+
+    #[test]
+    fn skips_blank_lines_after_operator_row() {
+        let input = b"123 328  51 64
+ 45 64  387 23
+  6 98  215 314
+*   +   *   +  
+
+";
+
+        assert_eq!(part_one(input).unwrap(), 4277556);
+        assert_eq!(part_two(input).unwrap(), 3263827);
+        assert_eq!(
+            parse_part1(b"\n  \n\n").unwrap_err().to_string(),
+            "expected number rows followed by an operator row"
+        );
+    }
+
+    #[test]
+    fn reports_unknown_operator() {
+        let input = b"123 328  51 64
+ 45 64  387 23
+  6 98  215 314
+*   +   /   +  ";
+
+        assert_eq!(
+            parse_part1(input).unwrap_err().to_string(),
+            "unknown operator '/' at line 4, column 9"
+        );
+        assert_eq!(
+            parse_part2(b"*   +\n").unwrap_err().to_string(),
+            "expected number rows before the operator row at line 1"
+        );
+        assert_eq!(
+            parse_part2(b"").unwrap_err().to_string(),
+            "expected number rows followed by an operator row"
+        );
+        assert_eq!(
+            parse_part1(&b"1\n".repeat(18)).unwrap_err().to_string(),
+            "found 17 number rows, at most 16 are supported at line 17"
+        );
+    }
+
+    #[test]
+    fn parsing_lines() {
+        let mut input = [b' ';200];
+        input[121] = b'\n';
+        input[160] = b'\n';
+
+        assert_eq!(
+            line(&input),
+            (&input[..121], &input[122..])
+        );
+    }
+
+    #[test]
+    fn parsing_frames() {
+        let input = b"*   *   *  +  *  *   *   +  +   +    +    +   +  +    *   +  +   +  +    *  +   ";
+
+        assert_eq!(
+            frames(input),
+            vec![
+                (b'*', 0..3),
+                (b'*', 4..7),
+                (b'*', 8..10),
+                (b'+', 11..13),
+                (b'*', 14..16),
+                (b'*', 17..20),
+                (b'*', 21..24),
+                (b'+', 25..27),
+                (b'+', 28..31),
+                (b'+', 32..36),
+                (b'+', 37..41),
+                (b'+', 42..45),
+                (b'+', 46..48),
+                (b'+', 49..53),
+                (b'*', 54..57),
+                (b'+', 58..60),
+                (b'+', 61..64),
+                (b'+', 65..67),
+                (b'+', 68..72),
+                (b'*', 73..75),
+                (b'+', 76..80),
+            ]
+        );
+    }
+}
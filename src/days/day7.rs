@@ -0,0 +1,186 @@
+use std::fmt::Display;
+use std::simd::prelude::*;
+use crate::{lines, Error, Grid, Solution};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input<'a> = Grid<u8>;
+
+    fn parse(input: &[u8]) -> Result<Self::Input<'_>, Error> {
+        Grid::parse(lines(input).strip_cr().skip_blank(), |cell| matches!(cell, EMPTY | START | SPLITTER).then_some(cell))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Option<Result<impl Display, Error>> {
+        Some(part2(input))
+    }
+}
+
+const BEAM: u8 = b'|';
+const START: u8 = b'S';
+const SPLITTER: u8 = b'^';
+const EMPTY: u8 = b'.';
+const SPLITTER_PATTERN: u8x64 = u8x64::splat(SPLITTER);
+const BEAM_PATTERN: u8x64 = u8x64::splat(BEAM);
+const DEFAULT_EMPTY: u8x64 = u8x64::splat(EMPTY);
+
+fn part1(input: &Grid<u8>) -> Result<u64, Error> {
+    let mut count= 0;
+    process_beam(input, |_,_,_| count += 1)?;
+    Ok(count)
+}
+
+fn part2(input: &Grid<u8>) -> Result<u64, Error> {
+    let mut counts = vec![0; input.width()];
+
+    process_beam(input, |left,from,right| {
+        if counts[from] == 0 {
+            counts[from] = 1;
+        }
+
+        counts[left] += counts[from];
+        counts[right] += counts[from];
+        counts[from] = 0;
+    })?;
+
+    Ok(counts.iter().sum())
+}
+
+/// Follows the beam row by row, starting from the `S` in the first row
+fn process_beam<F: FnMut(usize, usize, usize)>(
+    input: &Grid<u8>,
+    mut split_beam: F) -> Result<(), Error> {
+
+    let mut rows = input.rows().enumerate();
+    let (_, first_line) = rows.next().ok_or_else(|| Error::new("no first line"))?;
+    let width = input.width();
+    let mut beam_line = first_line.to_vec();
+    if let Some(position) = beam_line.iter().position(|&b| b == START) {
+        beam_line[position] = BEAM;
+    }
+    let mut current_beam_line = beam_line.clone();
+
+    for (row, mut current_line) in rows {
+        current_beam_line.copy_from_slice(&beam_line);
+        let mut offset = 0;
+        while !current_line.is_empty() {
+            let next_chunk = 64.min(current_line.len());
+            let mut beam = beam_is_split(
+                &current_line[..next_chunk],
+                &current_beam_line[offset..offset+next_chunk],
+            );
+
+            let mut splitter = 0;
+            while beam.trailing_zeros() < 64 {
+                splitter += beam.trailing_zeros() as usize;
+                beam >>= beam.trailing_zeros();
+                if beam.trailing_ones() > 0 {
+                    beam >>= 1;
+                }
+                let index = offset + splitter;
+                if index == 0 || index + 1 >= width {
+                    return Err(Error::new("splitter sends beam outside of the manifold").at(row + 1, index + 1));
+                }
+                beam_line[index - 1..=index+1].copy_from_slice(&[BEAM, EMPTY, BEAM]);
+                split_beam(index - 1, index, index+1);
+                splitter+=1;
+            }
+
+            current_line=&current_line[next_chunk..];
+            offset+= next_chunk;
+        }
+    }
+
+    Ok(())
+}
+
+
+fn beam_is_split(line: &[u8], beam: &[u8]) -> u64 {
+    (u8x64::load_or(line, DEFAULT_EMPTY).simd_eq(SPLITTER_PATTERN) &
+    u8x64::load_or(beam, DEFAULT_EMPTY).simd_eq(BEAM_PATTERN)).to_bitmask()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_test_case() {
+        let input = b".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+
+        assert_eq!(part1(&Day7::parse(input).unwrap()).unwrap(), 21);
+    }
+
+    #[test]
+    fn part2_test_case() {
+        let input = b".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+
+        assert_eq!(part2(&Day7::parse(input).unwrap()).unwrap(), 40);
+    }
+
+    #[test]
+    fn reports_malformed_manifold() {
+        assert_eq!(part1(&Day7::parse(b"").unwrap()).unwrap_err().to_string(), "no first line");
+        assert_eq!(part1(&Day7::parse(b"\n\n").unwrap()).unwrap_err().to_string(), "no first line");
+        assert_eq!(Day7::parse(b"S\n.\n\n").unwrap(), Day7::parse(b"S\n.").unwrap());
+        assert_eq!(
+            part1(&Day7::parse(b"S..\n^..").unwrap()).unwrap_err().to_string(),
+            "splitter sends beam outside of the manifold at line 2, column 1"
+        );
+        assert_eq!(
+            Day7::parse(b"..S..\n...\n").unwrap_err().to_string(),
+            "row is 3 cells wide, expected 5 at line 2"
+        );
+        assert_eq!(Day7::parse(b"..S..\n.|...\n").unwrap_err().to_string(), "unexpected cell '|' at line 2, column 2");
+    }
+
+    #[test]
+    fn follows_beam_through_wide_manifold() {
+        // Splitters on both sides of a 64 column chunk border, in a manifold wider than 256 columns
+        let width = 400;
+        let mut rows = vec![vec![b'.'; width]; 6];
+        rows[0][320] = b'S';
+        rows[2][320] = b'^';
+        rows[4][319] = b'^';
+        rows[4][321] = b'^';
+        let input = Day7::parse(&rows.join(&b'\n')).unwrap();
+
+        assert_eq!(part1(&input).unwrap(), 3);
+        assert_eq!(part2(&input).unwrap(), 4);
+    }
+}
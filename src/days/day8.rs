@@ -1,15 +1,11 @@
-#![feature(portable_simd)]
-extern crate core;
-
 use std::collections::BTreeMap;
+use std::fmt::Display;
 use std::ops::ControlFlow;
 use std::simd::prelude::*;
-use aoc2025::{lines, measure, Lines, NumberExt, Task};
-
-const PATTERN: u8x32 = u8x32::splat(b',');
+use crate::{separated_lines, Error, Solution};
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
-struct Coordinate(u64, u64, u64);
+pub struct Coordinate(u64, u64, u64);
 
 impl Coordinate {
     fn distance(&self, other: &Coordinate) -> u64 {
@@ -20,10 +16,7 @@ impl Coordinate {
     }
 }
 
-#[derive(Clone,Copy)]
-struct Parser<'a> {
-    lines: Lines<'a>,
-}
+type Circuits = Vec<Vec<Coordinate>>;
 
 struct HeapWithLimit {
     items: BTreeMap<u64, (Coordinate, Coordinate)>
@@ -41,30 +34,7 @@ impl HeapWithLimit {
     }
 
     fn iter(&self) -> impl Iterator<Item=(Coordinate, Coordinate)> {
-        self.items.iter().map(|(_k,v)| (v.0, v.1))
-    }
-}
-
-impl Iterator for Parser<'_> {
-    type Item = Coordinate;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        match self.lines.next() {
-            Some(mut line) => {
-                let mut scan = u8x32::load_or_default(&line[..]).simd_eq(PATTERN).to_bitmask();
-                if scan.count_ones() != 2 {
-                    return None;
-                }
-                let mut coordinate = Coordinate::default();
-
-                (coordinate.0, line) = read_int(line, &mut scan);
-                (coordinate.1, line) = read_int(line, &mut scan);
-                (coordinate.2, _) = read_int(line, &mut scan);
-
-                Some(coordinate)
-            },
-            None => None,
-        }
+        self.items.values().map(|v| (v.0, v.1))
     }
 }
 
@@ -73,52 +43,53 @@ fn distance(left: &u64x4, right: &u64x4) -> u64 {
     (diff*diff).reduce_sum()
 }
 
-fn parse_input(input: &[u8]) -> Parser<'_> {
-    Parser { lines: lines(input) }
+fn parse_input(input: &[u8]) -> Result<Vec<Coordinate>, Error> {
+    separated_lines(input, b',').map(|coordinate| coordinate.map(|[x, y, z]| Coordinate(x, y, z))).collect()
 }
 
-fn read_int<'a>(line: &'a[u8], scan: &mut u64) -> (u64, &'a[u8]) {
-    if *scan == 0 {
-        return (u64::from_bytes(&line[..]), &[]);
-    }
+pub struct Day8;
 
-    let position = scan.trailing_zeros();
-    *scan >>= position;
-    *scan >>= scan.trailing_ones();
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input<'a> = Vec<Coordinate>;
 
-    (u64::from_bytes(&line[..position as usize]), &line[position as usize + 1..])
-}
+    fn parse(input: &[u8]) -> Result<Self::Input<'_>, Error> {
+        parse_input(input)
+    }
 
-fn main() {
-    let input = parse_input(include_bytes!("../../inputs/day8.txt"));
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        Ok(part_one(input, 1000))
+    }
 
-    measure(Task::Part1, || part_one(input, 1000));
-    measure(Task::Part2, || part_two(input));
+    fn part2(input: &Self::Input<'_>) -> Option<Result<impl Display, Error>> {
+        Some(part_two(input))
+    }
 }
 
-fn part_one(parser: Parser, limit: usize) -> usize {
-    let (distance, _total) = calculate_initial_state(parser);
+fn part_one(junction_boxes: &[Coordinate], limit: usize) -> usize {
+    let (distance, _total) = calculate_initial_state(junction_boxes);
 
     let mut circuits = distance.iter().take(limit).fold(Vec::new(), connect_circuit);
-    circuits.sort_by(|a, b| b.len().cmp(&a.len()));
+    circuits.sort_by_key(|circuit| std::cmp::Reverse(circuit.len()));
 
     circuits.into_iter().take(3).fold(1, |acc, v| acc * v.len())
 }
 
-fn part_two(parser: Parser) -> u64 {
-    let (distance, total) = calculate_initial_state(parser);
+fn part_two(junction_boxes: &[Coordinate]) -> Result<u64, Error> {
+    let (distance, total) = calculate_initial_state(junction_boxes);
 
     let result = distance.iter().try_fold(
         Vec::new(),
         connect_circuit_until_circuit_length(total)
     );
 
-    let value = result.break_value().expect("Must be set after iterations");
-    value.0.0 * value.1.0
+    let value = result.break_value()
+        .ok_or_else(|| Error::new(format!("{total} junction boxes never connect into a single circuit")))?;
+    Ok(value.0.0 * value.1.0)
 }
 
-fn calculate_initial_state(parser: Parser) -> (HeapWithLimit, usize) {
-    let (junction_boxes, distance) = parser.fold(
+fn calculate_initial_state(junction_boxes: &[Coordinate]) -> (HeapWithLimit, usize) {
+    let (junction_boxes, distance) = junction_boxes.iter().copied().fold(
         (Vec::new(), HeapWithLimit::new()),
         |(mut vectors, v), line| {
             let v = vectors.iter().fold(v, |mut v, other| {
@@ -135,12 +106,12 @@ fn calculate_initial_state(parser: Parser) -> (HeapWithLimit, usize) {
     (distance, junctions)
 }
 
-fn connect_circuit(mut circuits: Vec<Vec<Coordinate>>, (left, right): (Coordinate, Coordinate)) -> Vec<Vec<Coordinate>> {
+fn connect_circuit(mut circuits: Circuits, (left, right): (Coordinate, Coordinate)) -> Circuits {
     let _ = connect_pair(&mut circuits, left, right);
     circuits
 }
 
-fn connect_circuit_until_circuit_length(limit: usize) -> impl FnMut(Vec<Vec<Coordinate>>, (Coordinate, Coordinate)) -> ControlFlow<(Coordinate, Coordinate), Vec<Vec<Coordinate>>> {
+fn connect_circuit_until_circuit_length(limit: usize) -> impl FnMut(Circuits, (Coordinate, Coordinate)) -> ControlFlow<(Coordinate, Coordinate), Circuits> {
     move |mut circuits, (left, right)| {
         match connect_pair(&mut circuits, left, right) {
             Some(position) if circuits[position].len() == limit => ControlFlow::Break((left, right)),
@@ -149,13 +120,13 @@ fn connect_circuit_until_circuit_length(limit: usize) -> impl FnMut(Vec<Vec<Coor
     }
 }
 
-fn connect_pair(circuits: &mut Vec<Vec<Coordinate>>, left: Coordinate, right: Coordinate) -> Option<usize> {
+fn connect_pair(circuits: &mut Circuits, left: Coordinate, right: Coordinate) -> Option<usize> {
     let circuit = (
         circuits.iter().position(|v| v.contains(&left)),
         circuits.iter().position(|v| v.contains(&right)),
     );
 
-    let position = match circuit {
+    match circuit {
         (Some(source), Some(dest)) if source != dest => {
             let remove = source.max(dest);
             let keep = source.min(dest);
@@ -177,15 +148,14 @@ fn connect_pair(circuits: &mut Vec<Vec<Coordinate>>, left: Coordinate, right: Co
             Some(circuits.len() - 1)
         }
         _ => None
-    };
-    position
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    fn test_data() -> Parser<'static> {
+    fn test_data() -> Vec<Coordinate> {
         parse_input(b"162,817,812
 57,618,57
 906,360,560
@@ -205,7 +175,7 @@ mod tests {
 941,993,340
 862,61,35
 984,92,344
-425,690,689")
+425,690,689").unwrap()
     }
 
     #[test]
@@ -222,7 +192,7 @@ mod tests {
     #[test]
     fn test_case_part1() {
         assert_eq!(
-            part_one(test_data(), 10),
+            part_one(&test_data(), 10),
             40
         );
     }
@@ -230,7 +200,7 @@ mod tests {
     #[test]
     fn test_case_part2() {
         assert_eq!(
-            part_two(test_data()),
+            part_two(&test_data()).unwrap(),
             25272
         );
     }
@@ -238,7 +208,7 @@ mod tests {
     #[test]
     fn parses_numbers() {
         assert_eq!(
-            test_data().collect::<Vec<_>>(),
+            test_data(),
             vec![
                 Coordinate(162, 817, 812),
                 Coordinate(57, 618, 57),
@@ -263,4 +233,20 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn reports_malformed_coordinates() {
+        assert_eq!(
+            parse_input(b"162,817,812\n57,618").unwrap_err().to_string(),
+            "expected 3 numbers separated by ',' at line 2, column 7"
+        );
+        assert_eq!(
+            parse_input(b"162,817,812\n57,6x8,3").unwrap_err().to_string(),
+            "unexpected 'x' in a number at line 2, column 5"
+        );
+        assert_eq!(
+            part_two(&[Coordinate(1, 2, 3)]).unwrap_err().to_string(),
+            "1 junction boxes never connect into a single circuit"
+        );
+    }
 }
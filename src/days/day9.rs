@@ -0,0 +1,78 @@
+use std::fmt::Display;
+use std::ops::Add;
+use std::simd::prelude::*;
+use crate::{separated_lines, Error, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input<'a> = Vec<(u64, u64)>;
+
+    fn parse(input: &[u8]) -> Result<Self::Input<'_>, Error> {
+        separated_lines(input, b',').map(|tile| tile.map(|[x, y]| (x, y))).collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+        Ok(part1(input))
+    }
+}
+
+const ONES: u64x16 = u64x16::splat(1);
+
+fn part1(input: &[(u64, u64)]) -> u64 {
+    let coords: (Vec<_>, Vec<_>) = input.iter().copied().unzip();
+    let items = 0..coords.0.len();
+    let mut best_rectangle = 0;
+
+    for current_item in items {
+        let (current_x, current_y) = (
+            u64x16::splat(coords.0[current_item]),
+            u64x16::splat(coords.1[current_item])
+        );
+
+        let mut chunk_x = coords.0.chunks(16);
+        let mut chunk_y = coords.1.chunks(16);
+
+        while let (Some(chunk_x), Some(chunk_y)) = (chunk_x.next(), chunk_y.next()) {
+            let len_x = u64x16::load_or(chunk_x, current_x).abs_diff(current_x).add(ONES);
+            let len_y = u64x16::load_or(chunk_y, current_y).abs_diff(current_y).add(ONES);
+            let area = len_x * len_y;
+            best_rectangle = best_rectangle.max(area.reduce_max())
+        }
+    }
+
+    best_rectangle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_data() -> Vec<(u64, u64)> {
+        Day9::parse(b"7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3").unwrap()
+    }
+
+    #[test]
+    fn test_case_part1() {
+        assert_eq!(
+            part1(&test_data()),
+            50
+        );
+    }
+
+    #[test]
+    fn reports_malformed_numbers() {
+        assert_eq!(
+            Day9::parse(b"7,1\n11,99999999999999999999").unwrap_err().to_string(),
+            "number does not fit into u64 at line 2, column 4"
+        );
+    }
+}
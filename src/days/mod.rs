@@ -0,0 +1,76 @@
+use crate::{run, Bench, Error, Outcome, Solution, Task};
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+type Runner = fn(&[u8], &[Task], Bench) -> Result<Vec<Outcome>, Error>;
+
+/// Registered solution with its type erased, so every day can be stored in one table
+pub struct Day {
+    pub number: u8,
+    runner: Runner,
+}
+
+impl Day {
+    const fn of<S: Solution>() -> Self {
+        Self {
+            number: S::DAY,
+            runner: run::<S>,
+        }
+    }
+
+    pub fn run(&self, input: &[u8], tasks: &[Task], bench: Bench) -> Result<Vec<Outcome>, Error> {
+        (self.runner)(input, tasks, bench)
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::of::<day1::Day1>(),
+    Day::of::<day2::Day2>(),
+    Day::of::<day3::Day3>(),
+    Day::of::<day4::Day4>(),
+    Day::of::<day5::Day5>(),
+    Day::of::<day6::Day6>(),
+    Day::of::<day7::Day7>(),
+    Day::of::<day8::Day8>(),
+    Day::of::<day9::Day9>(),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers_each_day_once() {
+        let numbers = DAYS.iter().map(|day| day.number).collect::<Vec<_>>();
+
+        assert_eq!(numbers, (1..=9).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn runs_registered_day() {
+        let outcomes = find(9).unwrap().run(b"7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3", &[Task::Part1, Task::Part2], Bench::ONCE).unwrap();
+
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].task, Task::Part1);
+        assert_eq!(outcomes[0].answer, Ok("50".into()));
+    }
+
+    #[test]
+    fn reports_malformed_input_with_day() {
+        let error = find(9).unwrap().run(b"7,1\n11", &[Task::Part1], Bench::ONCE).unwrap_err();
+
+        assert_eq!(error.to_string(), "day9: expected 2 numbers separated by ',' at line 2, column 3");
+    }
+}
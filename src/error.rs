@@ -0,0 +1,109 @@
+use std::fmt::Display;
+
+/// Malformed puzzle input or an input without an answer, located by 1-based line and column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    day: Option<u8>,
+    line: Option<usize>,
+    column: Option<usize>,
+    message: String,
+}
+
+impl Error {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn at(self, line: usize, column: usize) -> Self {
+        self.at_line(line).at_column(column)
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    /// Moves reported location by the 1-based line and column where the parsed slice starts, 0 counts as 1
+    pub fn offset(mut self, line: usize, column: usize) -> Self {
+        self.line = Some(self.line.unwrap_or(1) + line.saturating_sub(1));
+        self.offset_column(column)
+    }
+
+    /// Moves reported column only, for single line input where the line is not worth reporting,
+    /// `column` is 1-based like in [`Error::offset`]
+    pub fn offset_column(mut self, column: usize) -> Self {
+        self.column = Some(self.column.unwrap_or(1) + column.saturating_sub(1));
+        self
+    }
+
+    pub fn in_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        if let Some(day) = self.day {
+            write!(formatter, "day{day}: ")?;
+        }
+        formatter.write_str(&self.message)?;
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(formatter, " at line {line}, column {column}"),
+            (Some(line), None) => write!(formatter, " at line {line}"),
+            (None, Some(column)) => write!(formatter, " at column {column}"),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_location_and_day() {
+        assert_eq!(Error::new("unknown operator '/'").at_column(14).in_day(6).to_string(), "day6: unknown operator '/' at column 14");
+        assert_eq!(Error::new("no first line").in_day(7).to_string(), "day7: no first line");
+        assert_eq!(Error::new("expected digit").at(3, 2).to_string(), "expected digit at line 3, column 2");
+        assert_eq!(Error::new("empty line").at_line(5).to_string(), "empty line at line 5");
+    }
+
+    #[test]
+    fn offsets_location_into_input() {
+        assert_eq!(Error::new("expected digit").at_column(2).offset(4, 10), Error::new("expected digit").at(4, 11));
+        assert_eq!(Error::new("expected digit").offset(4, 10), Error::new("expected digit").at(4, 10));
+        assert_eq!(Error::new("expected digit").at_column(2).offset_column(10), Error::new("expected digit").at_column(11));
+    }
+
+    #[test]
+    fn treats_zero_offsets_as_first_position() {
+        assert_eq!(Error::new("expected digit").at(3, 2).offset(0, 0), Error::new("expected digit").at(3, 2));
+        assert_eq!(Error::new("expected digit").offset(0, 0), Error::new("expected digit").at(1, 1));
+        assert_eq!(Error::new("expected digit").offset_column(0), Error::new("expected digit").at_column(1));
+    }
+}
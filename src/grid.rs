@@ -0,0 +1,185 @@
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use crate::{Error, Lines};
+
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const SURROUNDING: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+/// Rectangular map stored row by row, positions are `(row, column)` counted from 0
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cell: T) -> Self where T: Clone {
+        Self { width, height, cells: vec![cell; width * height] }
+    }
+
+    /// Converts every byte with `cell`, rows must be equally wide and errors point at 1-based line and column
+    pub fn parse(lines: Lines<'_>, mut cell: impl FnMut(u8) -> Option<T>) -> Result<Self, Error> {
+        let mut width = None;
+        let mut cells = Vec::new();
+
+        for (row, line) in lines.enumerate() {
+            let expected = *width.get_or_insert(line.len());
+            if line.len() != expected {
+                return Err(Error::new(format!("row is {} cells wide, expected {expected}", line.len())).at_line(row + 1));
+            }
+            for (column, byte) in line.iter().enumerate() {
+                let value = cell(*byte)
+                    .ok_or_else(|| Error::new(format!("unexpected cell '{}'", byte.escape_ascii())).at(row + 1, column + 1))?;
+                cells.push(value);
+            }
+        }
+
+        let width = width.unwrap_or_default();
+        Ok(Self { width, height: cells.len().checked_div(width).unwrap_or_default(), cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (row, column): (usize, usize)) -> Option<&T> {
+        (row < self.height && column < self.width).then(|| &self.cells[row * self.width + column])
+    }
+
+    pub fn get_mut(&mut self, (row, column): (usize, usize)) -> Option<&mut T> {
+        (row < self.height && column < self.width).then(|| &mut self.cells[row * self.width + column])
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item=&[T]> {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, column: usize) -> impl DoubleEndedIterator<Item=&T> {
+        assert!(column < self.width, "column {column} is outside of a grid {} wide", self.width);
+        self.cells[column..].iter().step_by(self.width)
+    }
+
+    /// Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item=(usize, usize)> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    /// Positions above, left, right and below that are inside of the grid
+    pub fn neighbours4(&self, position: (usize, usize)) -> impl Iterator<Item=(usize, usize)> + use<T> {
+        self.neighbours(position, &ORTHOGONAL)
+    }
+
+    /// Positions of all eight surrounding cells that are inside of the grid
+    pub fn neighbours8(&self, position: (usize, usize)) -> impl Iterator<Item=(usize, usize)> + use<T> {
+        self.neighbours(position, &SURROUNDING)
+    }
+
+    fn neighbours(&self, (row, column): (usize, usize), offsets: &'static [(isize, isize)]) -> impl Iterator<Item=(usize, usize)> + use<T> {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |(row_offset, column_offset)| {
+            let row = row.checked_add_signed(*row_offset).filter(|row| *row < height)?;
+            let column = column.checked_add_signed(*column_offset).filter(|column| *column < width)?;
+            Some((row, column))
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get(position)
+            .unwrap_or_else(|| panic!("position {position:?} is outside of a {width}x{height} grid"))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("position {position:?} is outside of a {width}x{height} grid"))
+    }
+}
+
+/// One line per row with cells written next to each other
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        for row in self.rows() {
+            for cell in row {
+                write!(formatter, "{cell}")?;
+            }
+            writeln!(formatter)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lines;
+    use super::*;
+
+    fn digit(byte: u8) -> Option<u8> {
+        byte.is_ascii_digit().then(|| byte - b'0')
+    }
+
+    #[test]
+    fn parses_and_displays_grid() {
+        let grid = Grid::parse(lines(b"123\r\n456\r\n").strip_cr(), digit).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [3, 6]);
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn parses_grids_wider_than_simd_lanes() {
+        let line = b"1".repeat(300);
+        let input = [&line[..], b"\n", &line[..]].concat();
+        let grid = Grid::parse(lines(&input), digit).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (300, 2));
+        assert_eq!(grid.positions().count(), 600);
+    }
+
+    #[test]
+    fn reports_malformed_grid() {
+        assert_eq!(
+            Grid::parse(lines(b"123\n4x6"), digit).unwrap_err().to_string(),
+            "unexpected cell 'x' at line 2, column 2"
+        );
+        assert_eq!(
+            Grid::parse(lines(b"123\n45"), digit).unwrap_err().to_string(),
+            "row is 2 cells wide, expected 3 at line 2"
+        );
+        assert_eq!(Grid::parse(lines(b""), digit).unwrap(), Grid::new(0, 0, 0));
+    }
+
+    #[test]
+    fn finds_neighbours_inside_grid() {
+        let mut grid = Grid::new(3, 3, 0);
+        grid[(1, 1)] = 5;
+
+        assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), [(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8((0, 2)).collect::<Vec<_>>(), [(0, 1), (1, 1), (1, 2)]);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8((2, 2)).map(|position| grid[position]).sum::<i32>(), 5);
+    }
+}
@@ -0,0 +1,278 @@
+use std::fmt::Display;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use crate::runner::{csv_record, CSV_HEADER};
+use crate::{Outcome, Statistics, Task};
+
+const HISTORY_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/bench-history.csv");
+
+/// Outcomes of one runner invocation, identified by commit and unix timestamp
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    pub commit: String,
+    pub timestamp: u64,
+    pub outcomes: Vec<Outcome>,
+}
+
+#[derive(Debug)]
+pub struct HistoryError {
+    path: PathBuf,
+    line: usize,
+    message: String,
+}
+
+impl Display for HistoryError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self.line {
+            0 => write!(formatter, "{}: {}", self.path.display(), self.message),
+            line => write!(formatter, "{}:{line}: {}", self.path.display(), self.message),
+        }
+    }
+}
+
+impl std::error::Error for HistoryError {}
+
+pub fn default_history_path() -> PathBuf {
+    PathBuf::from(HISTORY_FILE)
+}
+
+impl Run {
+    /// Stamps outcomes with the checked out commit, marked `-dirty` when working tree has changes,
+    /// failed parts are left out so they never become a baseline
+    pub fn current(mut outcomes: Vec<Outcome>) -> Self {
+        outcomes.retain(|outcome| outcome.answer.is_ok());
+        let commit = std::process::Command::new("git")
+            .args(["describe", "--always", "--dirty"])
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+            .map(|commit| commit.trim().to_string())
+            .unwrap_or_else(|| "unknown".into());
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default();
+
+        Self { commit, timestamp, outcomes }
+    }
+
+    pub fn append(&self, path: &Path) -> Result<(), HistoryError> {
+        let error = |error: std::io::Error| HistoryError { path: path.into(), line: 0, message: error.to_string() };
+        let is_new = !path.exists();
+        let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path).map_err(error)?;
+
+        let mut content = String::new();
+        if is_new {
+            content.push_str(&format!("commit,timestamp,{CSV_HEADER}\n"));
+        }
+        for outcome in &self.outcomes {
+            content.push_str(&format!("{},{},{}\n", self.commit, self.timestamp, csv_record(outcome)));
+        }
+
+        file.write_all(content.as_bytes()).map_err(error)
+    }
+}
+
+/// All runs stored in the history file, oldest first
+pub fn read_history(path: &Path) -> Result<Vec<Run>, HistoryError> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|error| HistoryError { path: path.into(), line: 0, message: error.to_string() })?;
+
+    parse_history(&content)
+        .map_err(|(line, message)| HistoryError { path: path.into(), line, message })
+}
+
+fn parse_history(content: &str) -> Result<Vec<Run>, (usize, String)> {
+    let mut runs: Vec<Run> = Vec::new();
+
+    for (index, line) in content.lines().enumerate().skip(1) {
+        let line_number = index + 1;
+        if line.is_empty() {
+            continue;
+        }
+
+        let fields = split_record(line);
+        let [commit, timestamp, day, part, answer, iterations, timings @ ..] = fields.as_slice() else {
+            return Err((line_number, format!("expected 14 fields, found {}", fields.len())));
+        };
+        let number = |value: &str| value.parse::<u64>()
+            .map_err(|_| (line_number, format!("invalid number `{value}`")));
+        let timings = timings.iter().map(|value| number(value).map(Duration::from_nanos)).collect::<Result<Vec<_>, _>>()?;
+        let [parse_min, parse_median, parse_mean, parse_p99, solve_min, solve_median, solve_mean, solve_p99] = timings[..] else {
+            return Err((line_number, format!("expected 14 fields, found {}", fields.len())));
+        };
+        let samples = number(iterations)? as usize;
+        let timestamp = number(timestamp)?;
+
+        let outcome = Outcome {
+            day: number(day)? as u8,
+            task: Task::from_number(number(part)? as u8)
+                .ok_or_else(|| (line_number, format!("unknown part `{part}`")))?,
+            answer: Ok(answer.clone()),
+            parse: Statistics { samples, min: parse_min, median: parse_median, mean: parse_mean, p99: parse_p99 },
+            solve: Statistics { samples, min: solve_min, median: solve_median, mean: solve_mean, p99: solve_p99 },
+        };
+
+        match runs.last_mut() {
+            Some(run) if run.commit == *commit && run.timestamp == timestamp => run.outcomes.push(outcome),
+            _ => runs.push(Run { commit: commit.clone(), timestamp, outcomes: vec![outcome] }),
+        }
+    }
+
+    Ok(runs)
+}
+
+fn split_record(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match (char, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            },
+            ('"', _) => quoted = !quoted,
+            (',', false) => fields.push(String::new()),
+            (char, _) => fields.last_mut().unwrap().push(char),
+        }
+    }
+
+    fields
+}
+
+/// Latest run saved for a commit, `latest` picks the most recent run regardless of commit
+pub fn find_baseline<'a>(runs: &'a [Run], reference: &str) -> Option<&'a Run> {
+    runs.iter().rev().find(|run| reference == "latest" || run.commit.starts_with(reference))
+}
+
+/// Change of total median time of a part between baseline and current run
+#[derive(Debug, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub task: Task,
+    pub baseline: Duration,
+    pub current: Duration,
+    pub regression: bool,
+}
+
+impl Comparison {
+    pub fn change(&self) -> f64 {
+        (self.current.as_secs_f64() / self.baseline.as_secs_f64().max(f64::MIN_POSITIVE) - 1.0) * 100.0
+    }
+}
+
+/// Compares parts present in both runs, flagging those slower by more than threshold percent, failed parts are skipped
+pub fn compare(baseline: &Run, current: &[Outcome], threshold: f64) -> Vec<Comparison> {
+    current.iter().filter(|outcome| outcome.answer.is_ok()).filter_map(|outcome| {
+        let previous = baseline.outcomes.iter()
+            .find(|previous| previous.day == outcome.day && previous.task == outcome.task)?;
+
+        let mut comparison = Comparison {
+            day: outcome.day,
+            task: outcome.task,
+            baseline: previous.parse.median + previous.solve.median,
+            current: outcome.parse.median + outcome.solve.median,
+            regression: false,
+        };
+        comparison.regression = comparison.change() > threshold;
+        Some(comparison)
+    }).collect()
+}
+
+/// Comparison table printed after the results
+pub struct ComparisonReport<'a> {
+    pub baseline: &'a Run,
+    pub comparisons: &'a [Comparison],
+}
+
+impl Display for ComparisonReport<'_> {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        writeln!(formatter, "Compared with {} saved at {}", self.baseline.commit, self.baseline.timestamp)?;
+        writeln!(formatter, "Day | Part   | {:>12} | {:>12} | {:>9}", "Baseline", "Current", "Change")?;
+        writeln!(formatter, "----+--------+-{:->12}-+-{:->12}-+-{:->9}", "", "", "")?;
+        for comparison in self.comparisons {
+            writeln!(
+                formatter,
+                "{:>3} | {} | {:>12} | {:>12} | {:>9}{}",
+                comparison.day,
+                comparison.task,
+                format!("{:.2?}", comparison.baseline),
+                format!("{:.2?}", comparison.current),
+                format!("{:+.1}%", comparison.change()),
+                if comparison.regression { " slower" } else { "" },
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(day: u8, task: Task, answer: &str, nanos: u64) -> Outcome {
+        let timing = Statistics::single(Duration::from_nanos(nanos));
+        Outcome { day, task, answer: Ok(answer.into()), parse: timing, solve: timing }
+    }
+
+    #[test]
+    fn reads_back_saved_runs() {
+        let path = std::env::temp_dir().join(format!("aoc2025-{}-reads_back_saved_runs.csv", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let first = Run { commit: "abc123".into(), timestamp: 10, outcomes: vec![outcome(1, Task::Part1, "3", 100), outcome(2, Task::Part2, "6,5", 200)] };
+        let second = Run { commit: "def456-dirty".into(), timestamp: 20, outcomes: vec![outcome(1, Task::Part1, "3", 150)] };
+
+        first.append(&path).unwrap();
+        second.append(&path).unwrap();
+
+        assert_eq!(read_history(&path).unwrap(), vec![first, second]);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn reports_malformed_history_line() {
+        assert_eq!(
+            parse_history("header\nabc,10,1,1,3,1,0,0,0,0,0,0,0,x").unwrap_err(),
+            (2, "invalid number `x`".into())
+        );
+        assert_eq!(
+            parse_history("header\nabc,10,1,1").unwrap_err(),
+            (2, "expected 14 fields, found 4".into())
+        );
+    }
+
+    #[test]
+    fn finds_baseline_by_commit_prefix() {
+        let runs = [
+            Run { commit: "abc123".into(), timestamp: 10, outcomes: vec![] },
+            Run { commit: "def456".into(), timestamp: 20, outcomes: vec![] },
+            Run { commit: "abc123".into(), timestamp: 30, outcomes: vec![] },
+        ];
+
+        assert_eq!(find_baseline(&runs, "abc").map(|run| run.timestamp), Some(30));
+        assert_eq!(find_baseline(&runs, "def456").map(|run| run.timestamp), Some(20));
+        assert_eq!(find_baseline(&runs, "latest").map(|run| run.timestamp), Some(30));
+        assert_eq!(find_baseline(&runs, "fff"), None);
+    }
+
+    #[test]
+    fn flags_parts_slower_than_threshold() {
+        let baseline = Run { commit: "abc".into(), timestamp: 1, outcomes: vec![outcome(1, Task::Part1, "3", 100), outcome(1, Task::Part2, "4", 100)] };
+        let comparisons = compare(&baseline, &[outcome(1, Task::Part1, "3", 105), outcome(1, Task::Part2, "4", 150), outcome(2, Task::Part1, "5", 1)], 10.0);
+
+        assert_eq!(
+            comparisons,
+            vec![
+                Comparison { day: 1, task: Task::Part1, baseline: Duration::from_nanos(200), current: Duration::from_nanos(210), regression: false },
+                Comparison { day: 1, task: Task::Part2, baseline: Duration::from_nanos(200), current: Duration::from_nanos(300), regression: true },
+            ]
+        );
+    }
+}
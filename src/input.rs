@@ -0,0 +1,106 @@
+use std::fmt::Display;
+use std::io::{ErrorKind, Read};
+use std::path::PathBuf;
+
+const INPUT_DIRECTORY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/inputs");
+
+/// Where the puzzle input of a day is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Default(u8),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Resolves command line argument into a source, `-` stands for stdin
+    pub fn new(day: u8, argument: Option<&str>) -> Self {
+        match argument {
+            None => Self::Default(day),
+            Some("-") => Self::Stdin,
+            Some(path) => Self::File(path.into()),
+        }
+    }
+
+    pub fn read(&self) -> Result<Vec<u8>, InputError> {
+        let result = match self {
+            Self::Stdin => {
+                let mut buffer = Vec::new();
+                std::io::stdin().read_to_end(&mut buffer).map(|_| buffer)
+            },
+            Self::File(path) => std::fs::read(path),
+            Self::Default(day) => std::fs::read(default_path(*day)),
+        };
+
+        result.map_err(|error| InputError { source: self.clone(), error })
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Self::Default(day) => write!(formatter, "{}", default_path(*day).display()),
+            Self::File(path) => write!(formatter, "{}", path.display()),
+            Self::Stdin => formatter.write_str("stdin"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    source: InputSource,
+    error: std::io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match (&self.source, self.error.kind()) {
+            (InputSource::Default(day), ErrorKind::NotFound) => write!(
+                formatter,
+                "day{day}: input file {} is missing, save your puzzle input there, pass a path or `-` to read stdin",
+                self.source
+            ),
+            (source, _) => write!(formatter, "cannot read input from {source}: {}", self.error),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+pub fn default_path(day: u8) -> PathBuf {
+    PathBuf::from(INPUT_DIRECTORY).join(format!("day{day}.txt"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_input_source() {
+        assert_eq!(InputSource::new(3, None), InputSource::Default(3));
+        assert_eq!(InputSource::new(3, Some("-")), InputSource::Stdin);
+        assert_eq!(InputSource::new(3, Some("sample.txt")), InputSource::File("sample.txt".into()));
+    }
+
+    #[test]
+    fn reports_missing_default_input() {
+        let error = InputSource::Default(99).read().unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "day99: input file {}/day99.txt is missing, save your puzzle input there, pass a path or `-` to read stdin",
+                INPUT_DIRECTORY
+            )
+        );
+    }
+
+    #[test]
+    fn reads_input_from_file() {
+        let path = std::env::temp_dir().join(format!("aoc2025-{}-reads_input_from_file.txt", std::process::id()));
+        std::fs::write(&path, b"1,2\n3,4").unwrap();
+
+        assert_eq!(InputSource::File(path.clone()).read().unwrap(), b"1,2\n3,4");
+        std::fs::remove_file(path).unwrap();
+    }
+}
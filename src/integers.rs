@@ -0,0 +1,158 @@
+use std::marker::PhantomData;
+use std::simd::prelude::*;
+use crate::{lines, Error, SimdNumberExt};
+
+const ZERO: u8x32 = u8x32::splat(b'0');
+const TEN: u8x32 = u8x32::splat(10);
+
+/// Every integer in the input, any other byte separates them
+///
+/// For signed types a `-` or `+` right before the digits is the sign, unless it follows another digit,
+/// so `10-15` stays two numbers. Overflow is reported at the 1-based line and column of the number.
+#[derive(Clone, Copy)]
+pub struct Integers<'a, T> {
+    input: &'a [u8],
+    position: usize,
+    number: PhantomData<fn() -> T>,
+}
+
+pub fn integers<T: SimdNumberExt>(input: &[u8]) -> Integers<'_, T> {
+    Integers { input, position: 0, number: PhantomData }
+}
+
+impl<T: SimdNumberExt> Iterator for Integers<'_, T> {
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.position + find_digit(&self.input[self.position..], true)?;
+        let end = find_digit(&self.input[start..], false).map_or(self.input.len(), |length| start + length);
+        self.position = end;
+
+        let signed = T::SIGNED
+            && start > 0
+            && matches!(self.input[start - 1], b'-' | b'+')
+            && (start < 2 || !self.input[start - 2].is_ascii_digit());
+        let start = if signed { start - 1 } else { start };
+
+        Some(T::simd_from_bytes(&self.input[start..end]).map_err(|error| {
+            let line_start = self.input[..start].iter().rposition(|byte| *byte == b'\n').map_or(0, |position| position + 1);
+            let line = self.input[..line_start].iter().filter(|byte| **byte == b'\n').count() + 1;
+            error.offset(line, start - line_start + 1)
+        }))
+    }
+}
+
+/// Exactly `N` numbers split by `separator`, errors point at the 1-based column inside of the slice
+pub fn separated<T: SimdNumberExt + Copy + Default, const N: usize>(line: &[u8], separator: u8) -> Result<[T; N], Error> {
+    let mut values = [T::default(); N];
+    let mut rest = line;
+
+    for (index, value) in values.iter_mut().enumerate() {
+        let column = line.len() - rest.len() + 1;
+        let field = if index + 1 == N {
+            rest
+        } else {
+            let position = find_byte(rest, separator).ok_or_else(|| {
+                Error::new(format!("expected {N} numbers separated by '{}'", separator.escape_ascii())).at_column(line.len() + 1)
+            })?;
+            let field = &rest[..position];
+            rest = &rest[position + 1..];
+            field
+        };
+
+        *value = T::simd_from_bytes(field).map_err(|error| error.offset_column(column))?;
+    }
+
+    Ok(values)
+}
+
+/// [`separated`] on every line of the input that is not empty, errors point at the 1-based line and column
+pub fn separated_lines<T: SimdNumberExt + Copy + Default, const N: usize>(input: &[u8], separator: u8) -> impl Iterator<Item=Result<[T; N], Error>> + '_ {
+    lines(input).zip(1..)
+        .filter(|(line, _)| !line.is_empty())
+        .map(move |(line, row)| separated(line, separator).map_err(|error| error.offset(row, 1)))
+}
+
+/// Position of the first digit, or of the first byte that is not a digit
+fn find_digit(input: &[u8], digit: bool) -> Option<usize> {
+    for (index, chunk) in input.chunks(32).enumerate() {
+        let digits = (u8x32::load_or_default(chunk) - ZERO).simd_lt(TEN);
+        let found = if digit { digits } else { !digits };
+        if let Some(position) = found.first_set() {
+            let position = index * 32 + position;
+            return (position < input.len()).then_some(position);
+        }
+    }
+
+    None
+}
+
+fn find_byte(input: &[u8], byte: u8) -> Option<usize> {
+    let pattern = u8x32::splat(byte);
+    for (index, chunk) in input.chunks(32).enumerate() {
+        if let Some(position) = u8x32::load_or_default(chunk).simd_eq(pattern).first_set() {
+            let position = index * 32 + position;
+            return (position < input.len()).then_some(position);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_all_integers() {
+        assert_eq!(integers::<u64>(b"11-22,95-115\n998-1012").collect::<Result<Vec<_>, _>>(), Ok(vec![11, 22, 95, 115, 998, 1012]));
+        assert_eq!(integers::<u32>(b"p=0,4 v=3,-3").collect::<Result<Vec<_>, _>>(), Ok(vec![0, 4, 3, 3]));
+        assert_eq!(integers::<u8>(b"no numbers here").count(), 0);
+        assert_eq!(
+            integers::<u8>(b"a long line of text before the number, longer than one vector: 42").collect::<Result<Vec<_>, _>>(),
+            Ok(vec![42])
+        );
+    }
+
+    #[test]
+    fn finds_signed_integers() {
+        assert_eq!(integers::<i32>(b"p=0,4 v=3,-3").collect::<Result<Vec<_>, _>>(), Ok(vec![0, 4, 3, -3]));
+        assert_eq!(integers::<i64>(b"-5\n+7 10-15").collect::<Result<Vec<_>, _>>(), Ok(vec![-5, 7, 10, 15]));
+    }
+
+    #[test]
+    fn locates_overflowing_integer() {
+        assert_eq!(
+            integers::<u8>(b"1,2\n3,456").collect::<Result<Vec<_>, _>>(),
+            Err(Error::new("number does not fit into u8").at(2, 3))
+        );
+        assert_eq!(
+            integers::<i8>(b"1 -200").collect::<Result<Vec<_>, _>>(),
+            Err(Error::new("number does not fit into i8").at(1, 3))
+        );
+    }
+
+    #[test]
+    fn splits_exact_number_of_values() {
+        assert_eq!(separated::<u64, 3>(b"162,817,812", b','), Ok([162, 817, 812]));
+        assert_eq!(separated::<i16, 2>(b"-3--7", b'-').map_err(|error| error.to_string()), Err("expected a number at column 1".into()));
+        assert_eq!(separated::<i16, 2>(b"3,-7", b','), Ok([3, -7]));
+        assert_eq!(separated::<u64, 3>(b"57,618", b','), Err(Error::new("expected 3 numbers separated by ','").at_column(7)));
+        assert_eq!(separated::<u64, 2>(b"1,2,3", b','), Err(Error::new("unexpected ',' in a number").at_column(4)));
+        assert_eq!(separated::<u64, 2>(b"10,2x", b','), Err(Error::new("unexpected 'x' in a number").at_column(5)));
+    }
+
+    #[test]
+    fn splits_every_line() {
+        assert_eq!(separated_lines::<u64, 2>(b"7,1\n11,7", b',').collect::<Result<Vec<_>, _>>(), Ok(vec![[7, 1], [11, 7]]));
+        assert_eq!(separated_lines::<u64, 2>(b"7,1\n\n11,7\n\n", b',').collect::<Result<Vec<_>, _>>(), Ok(vec![[7, 1], [11, 7]]));
+        assert_eq!(
+            separated_lines::<u64, 2>(b"7,1\n\n11", b',').collect::<Result<Vec<_>, _>>(),
+            Err(Error::new("expected 2 numbers separated by ','").at(3, 3))
+        );
+        assert_eq!(
+            separated_lines::<u64, 2>(b"7,1\n11", b',').collect::<Result<Vec<_>, _>>(),
+            Err(Error::new("expected 2 numbers separated by ','").at(2, 3))
+        );
+    }
+}
@@ -0,0 +1,280 @@
+use std::cmp::{max, min};
+use std::ops::RangeInclusive;
+
+/// Integer that can bound an inclusive interval
+pub trait Endpoint: Copy + Ord {
+    const MIN: Self;
+    const MAX: Self;
+
+    fn successor(self) -> Option<Self>;
+    fn predecessor(self) -> Option<Self>;
+    /// Number of values from `self` to `end`, `end` included
+    fn count_to(self, end: Self) -> u128;
+}
+
+macro_rules! endpoint {
+    ($($number:ty),*) => {$(
+        impl Endpoint for $number {
+            const MIN: Self = <$number>::MIN;
+            const MAX: Self = <$number>::MAX;
+
+            fn successor(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn predecessor(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn count_to(self, end: Self) -> u128 {
+                end.abs_diff(self) as u128 + 1
+            }
+        }
+    )*};
+}
+
+endpoint!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// Set of integers kept as sorted inclusive ranges that neither overlap nor touch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T: Endpoint> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    /// Ranges sorted by their start are merged in one pass, anything else is sorted first
+    pub fn from_ranges(ranges: impl IntoIterator<Item=RangeInclusive<T>>) -> Self {
+        let mut ranges = ranges.into_iter().filter(|range| !range.is_empty()).collect::<Vec<_>>();
+        if !ranges.is_sorted_by_key(|range| *range.start()) {
+            ranges.sort_unstable_by_key(|range| *range.start());
+        }
+
+        Self::from_sorted(ranges)
+    }
+
+    fn from_sorted(ranges: impl IntoIterator<Item=RangeInclusive<T>>) -> Self {
+        let mut merged: Vec<RangeInclusive<T>> = Vec::new();
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if touches(*last.end(), *range.start()) => {
+                    if range.end() > last.end() {
+                        *last = *last.start()..=*range.end();
+                    }
+                },
+                _ => merged.push(range),
+            }
+        }
+
+        Self { ranges: merged }
+    }
+
+    /// Sorted ranges of the set, consecutive ranges have at least one value between them
+    pub fn ranges(&self) -> &[RangeInclusive<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of values in the set, `u128` so a set covering all of `u64` still fits
+    pub fn covered_len(&self) -> u128 {
+        self.ranges.iter().map(|range| range.start().count_to(*range.end())).sum()
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let (mut start, mut end) = range.into_inner();
+        let first = self.ranges.partition_point(|stored| !touches(*stored.end(), start));
+        let last = self.ranges.partition_point(|stored| touches(end, *stored.start()));
+        if first < last {
+            start = min(start, *self.ranges[first].start());
+            end = max(end, *self.ranges[last - 1].end());
+        }
+
+        self.ranges.splice(first..last, [start..=end]);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|range| *range.end() < value);
+        self.ranges.get(index).is_some_and(|range| *range.start() <= value)
+    }
+
+    /// Whether every value of `range` is in the set, an empty range always is
+    pub fn contains_range(&self, range: &RangeInclusive<T>) -> bool {
+        if range.is_empty() {
+            return true;
+        }
+
+        let index = self.ranges.partition_point(|stored| stored.end() < range.start());
+        self.ranges.get(index).is_some_and(|stored| stored.start() <= range.start() && range.end() <= stored.end())
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let (mut left, mut right) = (self.ranges.iter().peekable(), other.ranges.iter().peekable());
+        let sorted = std::iter::from_fn(|| match (left.peek(), right.peek()) {
+            (Some(first), Some(second)) if second.start() < first.start() => right.next(),
+            (Some(_), _) => left.next(),
+            (None, _) => right.next(),
+        });
+
+        Self::from_sorted(sorted.cloned())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut left, mut right) = (0, 0);
+        while let (Some(first), Some(second)) = (self.ranges.get(left), other.ranges.get(right)) {
+            let start = max(*first.start(), *second.start());
+            let end = min(*first.end(), *second.end());
+            if start <= end {
+                ranges.push(start..=end);
+            }
+            if first.end() < second.end() {
+                left += 1;
+            } else {
+                right += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement(T::MIN..=T::MAX))
+    }
+
+    /// Values of `bounds` that are not in the set
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> Self {
+        let (low, high) = bounds.into_inner();
+        let mut ranges = Vec::new();
+        let mut next = (low <= high).then_some(low);
+
+        for range in &self.ranges {
+            let Some(from) = next else { break };
+            if *range.start() > high {
+                break;
+            }
+            if *range.end() < from {
+                continue;
+            }
+            if let Some(to) = range.start().predecessor()
+                && from <= to {
+                ranges.push(from..=to);
+            }
+            next = range.end().successor();
+        }
+
+        if let Some(from) = next
+            && from <= high {
+            ranges.push(from..=high);
+        }
+
+        Self { ranges }
+    }
+}
+
+impl<T: Endpoint> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Endpoint> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item=RangeInclusive<T>>>(ranges: I) -> Self {
+        Self::from_ranges(ranges)
+    }
+}
+
+/// Whether a range starting at `start` overlaps or directly follows one ending at `end`
+fn touches<T: Endpoint>(end: T, start: T) -> bool {
+    end.successor().is_none_or(|next| start <= next)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::xorshift;
+    use super::*;
+
+    fn members(set: &IntervalSet<u8>) -> Vec<bool> {
+        (0..=u8::MAX).map(|value| set.contains(value)).collect()
+    }
+
+    fn random_set(state: &mut u64) -> IntervalSet<u8> {
+        let mut next = || xorshift(state);
+        let count = next() % 8;
+        (0..count).map(|_| {
+            let start = (next() % 256) as u8;
+            start..=start.saturating_add((next() % 40) as u8)
+        }).collect()
+    }
+
+    #[test]
+    fn normalises_overlapping_and_adjacent_ranges() {
+        let set = IntervalSet::from_ranges([16..=20, 3..=5, 12..=18, 10..=14, 6..=6, RangeInclusive::new(30, 29)]);
+
+        assert_eq!(set.ranges(), [3..=6, 10..=20]);
+        assert_eq!(set.covered_len(), 15);
+        assert!(set.contains(3) && set.contains(20) && !set.contains(7) && !set.contains(21));
+        assert!(set.contains_range(&(11..=19)) && !set.contains_range(&(5..=10)));
+        assert!(IntervalSet::<u64>::new().is_empty());
+    }
+
+    #[test]
+    fn inserts_into_normalised_ranges() {
+        let mut set = IntervalSet::from_ranges([1..=2, 10..=12, 20..=25]);
+
+        set.insert(4..=5);
+        assert_eq!(set.ranges(), [1..=2, 4..=5, 10..=12, 20..=25]);
+        set.insert(3..=9);
+        assert_eq!(set.ranges(), [1..=12, 20..=25]);
+        set.insert(14..=30);
+        assert_eq!(set.ranges(), [1..=12, 14..=30]);
+        set.insert(RangeInclusive::new(7, 3));
+        assert_eq!(set.ranges(), [1..=12, 14..=30]);
+    }
+
+    #[test]
+    fn handles_type_bounds() {
+        let set = IntervalSet::from_ranges([0..=u64::MAX]);
+        assert_eq!(set.covered_len(), 1 << 64);
+        assert!(set.complement(0..=u64::MAX).is_empty());
+
+        let set = IntervalSet::from_ranges([i8::MIN..=-100, 100..=i8::MAX]);
+        assert_eq!(set.complement(i8::MIN..=i8::MAX).ranges(), [-99..=99]);
+        assert_eq!(set.complement(-120..=-110).ranges(), []);
+        assert_eq!(IntervalSet::<i8>::new().complement(-5..=5).ranges(), [-5..=5]);
+    }
+
+    #[test]
+    fn matches_membership_of_every_value() {
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        for _ in 0..200 {
+            let (left, right) = (random_set(&mut state), random_set(&mut state));
+            let (left_members, right_members) = (members(&left), members(&right));
+            let check = |set: IntervalSet<u8>, expected: &dyn Fn(usize) -> bool| {
+                assert!(set.ranges().windows(2).all(|pair| !touches(*pair[0].end(), *pair[1].start())));
+                assert_eq!(members(&set), (0..256).map(expected).collect::<Vec<_>>());
+                assert_eq!(set.covered_len(), (0..256).filter(|value| expected(*value)).count() as u128);
+            };
+
+            check(left.union(&right), &|value| left_members[value] || right_members[value]);
+            check(left.intersection(&right), &|value| left_members[value] && right_members[value]);
+            check(left.difference(&right), &|value| left_members[value] && !right_members[value]);
+            check(left.complement(50..=200), &|value| (50..=200).contains(&value) && !left_members[value]);
+
+            let mut inserted = left.clone();
+            for range in right.ranges() {
+                inserted.insert(range.clone());
+            }
+            assert_eq!(inserted, left.union(&right));
+        }
+    }
+}
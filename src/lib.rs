@@ -1,30 +1,32 @@
 #![feature(portable_simd)]
 
 use std::fmt::Display;
-use std::ops::{AddAssign, MulAssign};
 use std::simd::prelude::*;
-use std::time::Instant;
 
-pub trait NumberExt {
-    fn from_bytes(slice: &[u8]) -> Self;
-}
-
-impl <T: MulAssign + AddAssign + From<u8> + Copy> NumberExt for T {
-    fn from_bytes(slice: &[u8]) -> Self {
-        let mut accum = 0u8.into();
-        let decimal = 10u8.into();
-        for byte in slice {
-            if *byte < b'0' {
-                continue
-            }
-            accum *= decimal;
-            accum += (byte - b'0').into();
-        }
-
-        accum
-    }
-}
+mod answers;
+mod bench;
+mod bit_grid;
+mod error;
+mod grid;
+mod history;
+mod input;
+mod integers;
+mod interval_set;
+mod number;
+mod runner;
+pub mod days;
 
+pub use answers::{default_answers_path, Answers, AnswersError, Mismatch};
+pub use bench::{measure, Bench, Measurement, Statistics};
+pub use bit_grid::{BitGrid, Edges, NeighbourCounter, Neighbourhood};
+pub use error::Error;
+pub use grid::Grid;
+pub use history::{compare, default_history_path, find_baseline, read_history, Comparison, ComparisonReport, HistoryError, Run};
+pub use input::{default_path, InputError, InputSource};
+pub use integers::{integers, separated, separated_lines, Integers};
+pub use interval_set::{Endpoint, IntervalSet};
+pub use number::{CheckedNumberExt, NumberExt, SignedNumberExt, SimdNumberExt};
+pub use runner::{run, Format, Outcome, Report};
 
 const NEW_LINES: u8x64 = u8x64::splat(b'\n');
 
@@ -42,27 +44,220 @@ pub fn line(input: &[u8]) -> (&[u8], &[u8]) {
     (input, &[])
 }
 
+/// Last line of the input and everything before it, a single trailing `\n` does not start another line
+/// while the remainder keeps its own, so an empty line before the last one survives
+pub fn last_line(input: &[u8]) -> (&[u8], &[u8]) {
+    let input = input.strip_suffix(b"\n").unwrap_or(input);
+    let mut chunk_end = input.len();
+    for scan in input.rchunks(64) {
+        let chunk_start = chunk_end - scan.len();
+        let new_lines = u8x64::load_or_default(scan).simd_eq(NEW_LINES).to_bitmask();
+        if new_lines != 0 {
+            let new_line = chunk_start + 63 - new_lines.leading_zeros() as usize;
+            return (&input[new_line + 1..], &input[..=new_line]);
+        }
+        chunk_end = chunk_start;
+    }
+
+    (input, &[])
+}
+
+/// Lines split on `\n` from either end, by default kept exactly as they are in the input
 #[derive(Clone, Copy)]
 pub struct Lines<'a> {
-    input: &'a[u8]
+    input: &'a[u8],
+    strip_cr: bool,
+    trim_end: bool,
+    skip_blank: bool,
 }
 
 pub fn lines(input: &[u8]) -> Lines<'_> {
-    Lines { input }
+    Lines { input, strip_cr: false, trim_end: false, skip_blank: false }
+}
+
+impl Lines<'_> {
+    /// Drops the `\r` left at the end of every line by Windows line endings
+    pub fn strip_cr(self) -> Self {
+        Self { strip_cr: true, ..self }
+    }
+
+    /// Drops all trailing ASCII whitespace, `\r` included
+    pub fn trim_end(self) -> Self {
+        Self { trim_end: true, ..self }
+    }
+
+    /// Leaves out lines that are empty once stripped, so enumerating no longer gives line numbers
+    pub fn skip_blank(self) -> Self {
+        Self { skip_blank: true, ..self }
+    }
+}
+
+impl <'a> Lines<'a> {
+    /// Counts the lines once up front, for callers that need [`ExactSizeIterator::len`],
+    /// so the modes have to be set before
+    pub fn counted(self) -> CountedLines<'a> {
+        let remaining = match self.input {
+            _ if self.skip_blank => self.count(),
+            [] => 0,
+            input => {
+                let new_lines = input.chunks(64)
+                    .map(|scan| u8x64::load_or_default(scan).simd_eq(NEW_LINES).to_bitmask().count_ones() as usize)
+                    .sum::<usize>();
+                new_lines + usize::from(!input.ends_with(b"\n"))
+            },
+        };
+
+        CountedLines { lines: self, remaining }
+    }
+}
+
+impl <'a> Lines<'a> {
+    fn clean(&self, mut line: &'a[u8]) -> Option<&'a[u8]> {
+        if self.strip_cr && let Some(stripped) = line.strip_suffix(b"\r") {
+            line = stripped;
+        }
+        if self.trim_end {
+            line = line.trim_ascii_end();
+        }
+
+        (!(self.skip_blank && line.is_empty())).then_some(line)
+    }
 }
 
 impl <'a> Iterator for Lines<'a> {
     type Item = &'a[u8];
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.input.len() {
-            0 => None,
-            _ => {
-                let (line, remainder) = line(self.input);
-                self.input = remainder;
-                Some(line)
+        while !self.input.is_empty() {
+            let (line, remainder) = line(self.input);
+            self.input = remainder;
+            if let Some(line) = self.clean(line) {
+                return Some(line);
             }
         }
+
+        None
+    }
+
+    /// Every line takes at least one byte, see [`Lines::counted`] for the exact number
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.input {
+            [] => (0, Some(0)),
+            input => (usize::from(!self.skip_blank), Some(input.len())),
+        }
+    }
+}
+
+impl DoubleEndedIterator for Lines<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while !self.input.is_empty() {
+            let (line, remainder) = last_line(self.input);
+            self.input = remainder;
+            if let Some(line) = self.clean(line) {
+                return Some(line);
+            }
+        }
+
+        None
+    }
+}
+
+/// [`Lines`] that know how many are left
+#[derive(Clone, Copy)]
+pub struct CountedLines<'a> {
+    lines: Lines<'a>,
+    remaining: usize,
+}
+
+impl <'a> Iterator for CountedLines<'a> {
+    type Item = &'a[u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.lines.next()?;
+        self.remaining -= 1;
+        Some(line)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl DoubleEndedIterator for CountedLines<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let line = self.lines.next_back()?;
+        self.remaining -= 1;
+        Some(line)
+    }
+}
+
+impl ExactSizeIterator for CountedLines<'_> {}
+
+/// Block of consecutive lines, knowing the 1-based line of the input where it starts
+#[derive(Clone, Copy)]
+pub struct Section<'a> {
+    pub line: usize,
+    input: &'a[u8],
+}
+
+impl<'a> Section<'a> {
+    pub fn lines(&self) -> Lines<'a> {
+        lines(self.input)
+    }
+}
+
+impl<'a> IntoIterator for Section<'a> {
+    type Item = &'a[u8];
+    type IntoIter = Lines<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.lines()
+    }
+}
+
+/// Blocks separated by one or more blank lines, whitespace only lines count as blank
+#[derive(Clone, Copy)]
+pub struct Sections<'a> {
+    input: &'a[u8],
+    line: usize,
+}
+
+pub fn sections(input: &[u8]) -> Sections<'_> {
+    Sections { input, line: 1 }
+}
+
+impl<'a> Iterator for Sections<'a> {
+    type Item = Section<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.input.is_empty() {
+            let (first, remainder) = line(self.input);
+            if !first.trim_ascii().is_empty() {
+                break;
+            }
+            self.input = remainder;
+            self.line += 1;
+        }
+        if self.input.is_empty() {
+            return None;
+        }
+
+        let start = self.input;
+        let mut rest = start;
+        let mut line_count = 0;
+        while !rest.is_empty() {
+            let (current, remainder) = line(rest);
+            if current.trim_ascii().is_empty() {
+                break;
+            }
+            rest = remainder;
+            line_count += 1;
+        }
+
+        let section = Section { line: self.line, input: &start[..start.len() - rest.len()] };
+        self.input = rest;
+        self.line += line_count;
+        Some(section)
     }
 }
 
@@ -71,9 +266,10 @@ pub fn digits(value: u64) -> u8 {
 }
 
 pub fn factors(value: u8) -> impl Iterator<Item=u8> {
-    (2..value).filter_map(move |factor| if value % factor == 0 { Some(factor) } else { None })
+    (2..value).filter(move |factor| value.is_multiple_of(*factor))
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Task
 {
     Part1,
@@ -89,12 +285,44 @@ impl Display for Task {
     }
 }
 
-pub fn measure<F: FnOnce() -> R, R>(task: Task, operation: F) where R: Display {
-    let start = Instant::now();
-    let result = operation();
-    let elapsed = start.elapsed();
+impl Task {
+    pub fn number(&self) -> u8 {
+        match self {
+            Task::Part1 => 1,
+            Task::Part2 => 2,
+        }
+    }
+
+    pub fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Task::Part1),
+            2 => Some(Task::Part2),
+            _ => None,
+        }
+    }
+}
+
+/// Puzzle of a single day, parsed once and shared between both parts
+pub trait Solution {
+    const DAY: u8;
+    type Input<'a>;
 
-    println!("Task {task} result is {result} and it took {} ms", elapsed.as_millis());
+    fn parse(input: &[u8]) -> Result<Self::Input<'_>, Error>;
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display, Error>;
+
+    /// Stays `None` until the second part of the puzzle is solved
+    fn part2(_input: &Self::Input<'_>) -> Option<Result<impl Display, Error>> {
+        None::<Result<u64, Error>>
+    }
+}
+
+/// Next value of a xorshift generator, random enough for generated inputs and tests against slow references
+pub fn xorshift(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
 }
 
 #[cfg(test)]
@@ -118,16 +346,6 @@ mod tests {
         assert_eq!(factors(9).collect::<Vec<_>>(), vec![3]);
     }
 
-    #[test]
-    fn builds_numbers() {
-        assert_eq!(u8::from_bytes(b"123"), 123);
-        assert_eq!(u16::from_bytes(b"1023"), 1023);
-        assert_eq!(u32::from_bytes(b"1023123123"), 1023123123);
-        assert_eq!(u32::from_bytes(b"   1023123123  "), 1023123123);
-        assert_eq!(u32::from_bytes(b"   1023123123"), 1023123123);
-        assert_eq!(u32::from_bytes(b"1023123123   "), 1023123123);
-    }
-
     #[test]
     fn lines_iterator() {
         let mut lines = lines(b"first\nsecond\nthird");
@@ -137,4 +355,84 @@ mod tests {
         assert_eq!(lines.next(), Some(b"third".as_ref()));
         assert_eq!(lines.next(), None)
     }
+
+    #[test]
+    fn finds_last_line() {
+        let mut input = [b'a'; 200];
+        input[10] = b'\n';
+        input[150] = b'\n';
+
+        assert_eq!(last_line(&input), (&input[151..], &input[..151]));
+        assert_eq!(last_line(&input[..151]), (&input[11..150], &input[..11]));
+        assert_eq!(last_line(b"single"), (&b"single"[..], &b""[..]));
+    }
+
+    #[test]
+    fn iterates_lines_from_both_ends() {
+        let mut lines = lines(b"first\nsecond\n\nthird\nfourth\n").counted();
+
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines.next_back(), Some(b"fourth".as_ref()));
+        assert_eq!(lines.next(), Some(b"first".as_ref()));
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines.next_back(), Some(b"third".as_ref()));
+        assert_eq!(lines.next_back(), Some(b"".as_ref()));
+        assert_eq!(lines.next_back(), Some(b"second".as_ref()));
+        assert_eq!(lines.next_back(), None);
+        assert_eq!(lines.next(), None);
+        assert_eq!(lines.len(), 0);
+    }
+
+    #[test]
+    fn reverses_lines_with_modes() {
+        let input = b"a\r\n\r\nb  \n\nc";
+
+        assert_eq!(lines(input).rev().collect::<Vec<_>>(), [&b"c"[..], b"", b"b  ", b"\r", b"a\r"]);
+        assert_eq!(lines(input).trim_end().skip_blank().rev().collect::<Vec<_>>(), [&b"c"[..], b"b", b"a"]);
+        assert_eq!(lines(input).strip_cr().skip_blank().counted().len(), 3);
+        assert_eq!(lines(input).counted().len(), lines(input).count());
+        assert_eq!(lines(input).size_hint(), (1, Some(input.len())));
+        assert_eq!(lines(b"").skip_blank().size_hint(), (0, Some(0)));
+
+        let mut skipping = lines(input).skip_blank().trim_end().counted();
+        assert_eq!(skipping.len(), 3);
+        assert_eq!(skipping.next_back(), Some(b"c".as_ref()));
+        assert_eq!(skipping.next(), Some(b"a".as_ref()));
+        assert_eq!(skipping.len(), 1);
+        assert_eq!(skipping.next_back(), Some(b"b".as_ref()));
+        assert_eq!((skipping.len(), skipping.next()), (0, None));
+    }
+
+    #[test]
+    fn splits_sections_on_blank_lines() {
+        let blocks = sections(b"\n1-3\n5-7\n\n  \r\n4\n6\r\n\nlast\n\n").collect::<Vec<_>>();
+
+        assert_eq!(blocks.iter().map(|section| section.line).collect::<Vec<_>>(), [2, 6, 9]);
+        assert_eq!(blocks[0].lines().collect::<Vec<_>>(), [&b"1-3"[..], b"5-7"]);
+        assert_eq!(blocks[1].lines().strip_cr().collect::<Vec<_>>(), [&b"4"[..], b"6"]);
+        assert_eq!(blocks[2].into_iter().collect::<Vec<_>>(), [&b"last"[..]]);
+        assert_eq!(sections(b"\n\n").count(), 0);
+    }
+
+    #[test]
+    fn strips_mixed_line_endings() {
+        let input = b"first\r\nsecond\nthird  \r\n\r\n\nlast\r";
+
+        assert_eq!(
+            lines(input).collect::<Vec<_>>(),
+            [&b"first\r"[..], b"second", b"third  \r", b"\r", b"", b"last\r"]
+        );
+        assert_eq!(
+            lines(input).strip_cr().collect::<Vec<_>>(),
+            [&b"first"[..], b"second", b"third  ", b"", b"", b"last"]
+        );
+        assert_eq!(
+            lines(input).trim_end().collect::<Vec<_>>(),
+            [&b"first"[..], b"second", b"third", b"", b"", b"last"]
+        );
+        assert_eq!(
+            lines(input).strip_cr().skip_blank().collect::<Vec<_>>(),
+            [&b"first"[..], b"second", b"third  ", b"last"]
+        );
+    }
 }
@@ -0,0 +1,253 @@
+use std::ops::{AddAssign, MulAssign, Neg, SubAssign};
+use std::simd::prelude::*;
+use crate::Error;
+
+/// Fast path for trusted input, bytes below `'0'` are skipped and nothing is validated
+pub trait NumberExt {
+    fn from_bytes(slice: &[u8]) -> Self;
+}
+
+impl <T: MulAssign + AddAssign + From<u8> + Copy> NumberExt for T {
+    fn from_bytes(slice: &[u8]) -> Self {
+        let mut accum = 0u8.into();
+        let decimal = 10u8.into();
+        for byte in slice {
+            if *byte < b'0' {
+                continue
+            }
+            accum *= decimal;
+            accum += (byte - b'0').into();
+        }
+
+        accum
+    }
+}
+
+/// Fast path for trusted signed input, a `-` before the first digit negates the number
+pub trait SignedNumberExt {
+    fn from_signed_bytes(slice: &[u8]) -> Self;
+}
+
+/// Negative numbers accumulate below zero, so `MIN` parses without overflowing its magnitude
+impl <T: MulAssign + AddAssign + SubAssign + From<i8> + Copy + Neg<Output = T>> SignedNumberExt for T {
+    fn from_signed_bytes(slice: &[u8]) -> Self {
+        let negative = slice.trim_ascii_start().first() == Some(&b'-');
+        let mut accum = 0i8.into();
+        let decimal = 10i8.into();
+        for byte in slice {
+            if *byte < b'0' {
+                continue
+            }
+            accum *= decimal;
+            let digit = ((byte - b'0') as i8).into();
+            match negative {
+                true => accum -= digit,
+                false => accum += digit,
+            }
+        }
+
+        accum
+    }
+}
+
+/// Validating parser for untrusted input
+///
+/// Leading and trailing ASCII whitespace is skipped, everything in between must be a decimal digit.
+/// Signed types also accept a single leading `-` or `+`. Errors point at the 1-based column inside of the slice.
+pub trait CheckedNumberExt: Sized {
+    const SIGNED: bool;
+
+    fn checked_from_bytes(slice: &[u8]) -> Result<Self, Error>;
+}
+
+macro_rules! checked_number {
+    ($signed:literal: $($number:ty),*) => {$(
+        impl CheckedNumberExt for $number {
+            const SIGNED: bool = $signed;
+
+            fn checked_from_bytes(slice: &[u8]) -> Result<Self, Error> {
+                let start = slice.iter().position(|byte| !byte.is_ascii_whitespace()).unwrap_or(slice.len());
+                let mut digits = slice[start..].trim_ascii_end();
+                let mut skipped = start;
+                let negative = $signed && digits.first() == Some(&b'-');
+                if $signed && matches!(digits.first(), Some(b'-' | b'+')) {
+                    digits = &digits[1..];
+                    skipped += 1;
+                }
+                if digits.is_empty() {
+                    return Err(Error::new("expected a number").at_column(skipped + 1));
+                }
+
+                // Negative numbers are accumulated below zero, so the minimum of the type still fits
+                let mut accum: $number = 0;
+                for (index, byte) in digits.iter().enumerate() {
+                    if !byte.is_ascii_digit() {
+                        return Err(Error::new(format!("unexpected '{}' in a number", byte.escape_ascii())).at_column(skipped + index + 1));
+                    }
+                    let digit = (byte - b'0') as $number;
+                    accum = accum.checked_mul(10)
+                        .and_then(|accum| if negative { accum.checked_sub(digit) } else { accum.checked_add(digit) })
+                        .ok_or_else(|| Error::new(concat!("number does not fit into ", stringify!($number))).at_column(start + 1))?;
+                }
+
+                Ok(accum)
+            }
+        }
+    )*};
+}
+
+checked_number!(false: u8, u16, u32, u64, u128, usize);
+checked_number!(true: i8, i16, i32, i64, i128, isize);
+
+/// Validating parser converting up to 16 digits at once with `std::simd`
+///
+/// Accepts and rejects exactly what [`CheckedNumberExt`] does and reports the same errors,
+/// longer numbers, signed types and malformed input fall back to the scalar parser.
+pub trait SimdNumberExt: CheckedNumberExt {
+    fn simd_from_bytes(slice: &[u8]) -> Result<Self, Error>;
+}
+
+macro_rules! simd_number {
+    ($($number:ty),*) => {$(
+        impl SimdNumberExt for $number {
+            fn simd_from_bytes(slice: &[u8]) -> Result<Self, Error> {
+                // Numbers with this many digits always fit, so only the digits need checking
+                const LIMIT: usize = if <$number>::MAX.ilog10() < 16 { <$number>::MAX.ilog10() as usize } else { 16 };
+
+                let digits = slice.trim_ascii();
+                if digits.is_empty() || digits.len() > LIMIT {
+                    return <$number>::checked_from_bytes(slice);
+                }
+
+                match simd_digits(digits) {
+                    Some(value) => Ok(value as $number),
+                    None => <$number>::checked_from_bytes(slice),
+                }
+            }
+        }
+    )*};
+}
+
+simd_number!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! scalar_number {
+    ($($number:ty),*) => {$(
+        impl SimdNumberExt for $number {
+            fn simd_from_bytes(slice: &[u8]) -> Result<Self, Error> {
+                <$number>::checked_from_bytes(slice)
+            }
+        }
+    )*};
+}
+
+scalar_number!(i8, i16, i32, i64, i128, isize);
+
+const ZERO: u8x16 = u8x16::splat(b'0');
+const TEN: u8x16 = u8x16::splat(10);
+
+/// Right aligns at most 16 digits in a vector padded with zeros and folds neighbouring lanes
+/// into 2, 4, 8 and finally 16 digit values, `None` when any byte is not a digit
+fn simd_digits(digits: &[u8]) -> Option<u64> {
+    let mut block = [b'0'; 16];
+    block[16 - digits.len()..].copy_from_slice(digits);
+    let values = u8x16::from_array(block) - ZERO;
+    if values.simd_ge(TEN).any() {
+        return None;
+    }
+
+    let values = values.cast::<u32>();
+    let pairs = simd_swizzle!(values, [0, 2, 4, 6, 8, 10, 12, 14]) * u32x8::splat(10)
+        + simd_swizzle!(values, [1, 3, 5, 7, 9, 11, 13, 15]);
+    let quads = simd_swizzle!(pairs, [0, 2, 4, 6]) * u32x4::splat(100)
+        + simd_swizzle!(pairs, [1, 3, 5, 7]);
+    let octets = simd_swizzle!(quads, [0, 2]) * u32x2::splat(10_000)
+        + simd_swizzle!(quads, [1, 3]);
+
+    Some(octets[0] as u64 * 100_000_000 + octets[1] as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_numbers() {
+        assert_eq!(u8::from_bytes(b"123"), 123);
+        assert_eq!(u16::from_bytes(b"1023"), 1023);
+        assert_eq!(u32::from_bytes(b"1023123123"), 1023123123);
+        assert_eq!(u32::from_bytes(b"   1023123123  "), 1023123123);
+        assert_eq!(u32::from_bytes(b"   1023123123"), 1023123123);
+        assert_eq!(u32::from_bytes(b"1023123123   "), 1023123123);
+    }
+
+    #[test]
+    fn builds_signed_numbers() {
+        assert_eq!(i16::from_signed_bytes(b"-123"), -123);
+        assert_eq!(i32::from_signed_bytes(b"  -1023123"), -1023123);
+        assert_eq!(i64::from_signed_bytes(b"+42"), 42);
+        assert_eq!(i64::from_signed_bytes(b"42"), 42);
+        assert_eq!(i8::from_signed_bytes(b"-128"), i8::MIN);
+        assert_eq!(i16::from_signed_bytes(b"-32768"), i16::MIN);
+        assert_eq!(i32::from_signed_bytes(b"-2147483648"), i32::MIN);
+        assert_eq!(i64::from_signed_bytes(b" -9223372036854775808"), i64::MIN);
+        assert_eq!(i64::from_signed_bytes(b"9223372036854775807"), i64::MAX);
+    }
+
+    #[test]
+    fn builds_checked_numbers() {
+        assert_eq!(u8::checked_from_bytes(b"255"), Ok(255));
+        assert_eq!(u32::checked_from_bytes(b"   1023123123  "), Ok(1023123123));
+        assert_eq!(u64::checked_from_bytes(b"\t42\r\n"), Ok(42));
+        assert_eq!(i16::checked_from_bytes(b"0032"), Ok(32));
+    }
+
+    #[test]
+    fn builds_checked_signed_numbers() {
+        assert_eq!(i16::checked_from_bytes(b"-32"), Ok(-32));
+        assert_eq!(i32::checked_from_bytes(b" +17 "), Ok(17));
+        assert_eq!(i8::checked_from_bytes(b"-128"), Ok(i8::MIN));
+        assert_eq!(i8::checked_from_bytes(b"127"), Ok(i8::MAX));
+        assert_eq!(i8::checked_from_bytes(b"-129"), Err(Error::new("number does not fit into i8").at_column(1)));
+        assert_eq!(i64::checked_from_bytes(b"-"), Err(Error::new("expected a number").at_column(2)));
+        assert_eq!(i64::checked_from_bytes(b"--1"), Err(Error::new("unexpected '-' in a number").at_column(2)));
+        assert_eq!(u64::checked_from_bytes(b"+1"), Err(Error::new("unexpected '+' in a number").at_column(1)));
+    }
+
+    #[test]
+    fn rejects_invalid_digits() {
+        assert_eq!(u32::checked_from_bytes(b"12a4"), Err(Error::new("unexpected 'a' in a number").at_column(3)));
+        assert_eq!(u32::checked_from_bytes(b" 1 2"), Err(Error::new("unexpected ' ' in a number").at_column(3)));
+        assert_eq!(u32::checked_from_bytes(b"-12"), Err(Error::new("unexpected '-' in a number").at_column(1)));
+        assert_eq!(u32::checked_from_bytes(b"  "), Err(Error::new("expected a number").at_column(3)));
+        assert_eq!(u32::checked_from_bytes(b""), Err(Error::new("expected a number").at_column(1)));
+    }
+
+    #[test]
+    fn builds_simd_numbers() {
+        assert_eq!(u64::simd_from_bytes(b"0"), Ok(0));
+        assert_eq!(u64::simd_from_bytes(b"  1234567890123456\n"), Ok(1234567890123456));
+        assert_eq!(u64::simd_from_bytes(b"18446744073709551615"), Ok(u64::MAX));
+        assert_eq!(u32::simd_from_bytes(b"4294967295"), Ok(u32::MAX));
+        assert_eq!(usize::simd_from_bytes(b"000000000000000042"), Ok(42));
+
+        for value in (0..64).map(|shift| (1u64 << shift) - 1).chain([7, 99, 100, 10_000_001, 99_999_999_999]) {
+            assert_eq!(u64::simd_from_bytes(value.to_string().as_bytes()), Ok(value));
+        }
+    }
+
+    #[test]
+    fn reports_same_errors_as_checked_parser() {
+        for input in [&b""[..], b"  ", b"12a4", b" 1 2", b"-12", b"1/", b"12:", b"42949672960", b"184467440737095516160"] {
+            assert_eq!(u64::simd_from_bytes(input), u64::checked_from_bytes(input));
+            assert_eq!(u32::simd_from_bytes(input), u32::checked_from_bytes(input));
+        }
+    }
+
+    #[test]
+    fn rejects_overflow() {
+        assert_eq!(u8::checked_from_bytes(b"256"), Err(Error::new("number does not fit into u8").at_column(1)));
+        assert_eq!(i8::checked_from_bytes(b" 128"), Err(Error::new("number does not fit into i8").at_column(2)));
+        assert_eq!(u64::checked_from_bytes(b"18446744073709551616"), Err(Error::new("number does not fit into u64").at_column(1)));
+        assert_eq!(u64::checked_from_bytes(b"18446744073709551615"), Ok(u64::MAX));
+    }
+}
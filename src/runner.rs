@@ -0,0 +1,339 @@
+use std::fmt::Display;
+use crate::{measure, Bench, Error, Solution, Statistics, Task};
+
+/// Answer to a single part of a day together with the time spent on it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outcome {
+    pub day: u8,
+    pub task: Task,
+    /// Error of a part that failed, the other parts of the day are still reported
+    pub answer: Result<String, Error>,
+    pub parse: Statistics,
+    pub solve: Statistics,
+}
+
+/// Parses input and solves requested parts, unsolved parts are left out and only a parse error fails the whole day
+pub fn run<S: Solution>(input: &[u8], tasks: &[Task], bench: Bench) -> Result<Vec<Outcome>, Error> {
+    let parsed = measure(bench, || S::parse(input));
+    let parsed_input = parsed.result.map_err(|error| error.in_day(S::DAY))?;
+
+    let mut outcomes = Vec::with_capacity(tasks.len());
+    for task in tasks {
+        let (answer, solve) = match task {
+            Task::Part1 => {
+                let solved = measure(bench, || S::part1(&parsed_input));
+                (Some(solved.result.map(|answer| answer.to_string())), solved.timing)
+            },
+            Task::Part2 => {
+                let solved = measure(bench, || S::part2(&parsed_input));
+                (solved.result.map(|answer| answer.map(|answer| answer.to_string())), solved.timing)
+            },
+        };
+
+        if let Some(answer) = answer {
+            outcomes.push(Outcome {
+                day: S::DAY,
+                task: *task,
+                answer: answer.map_err(|error| error.in_day(S::DAY)),
+                parse: parsed.timing,
+                solve,
+            });
+        }
+    }
+
+    Ok(outcomes)
+}
+
+/// How the runner prints outcomes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Table,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "table" => Some(Format::Table),
+            "json" => Some(Format::Json),
+            "csv" => Some(Format::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// Outcomes rendered in one of the formats, the table gets a timing breakdown when more than one sample was taken
+pub struct Report<'a> {
+    outcomes: &'a [Outcome],
+    format: Format,
+}
+
+impl<'a> Report<'a> {
+    pub fn new(outcomes: &'a [Outcome], format: Format) -> Self {
+        Self { outcomes, format }
+    }
+
+    fn table(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        let width = self.outcomes.iter()
+            .map(|outcome| answer_text(outcome).len())
+            .max()
+            .unwrap_or_default()
+            .max("Answer".len());
+
+        writeln!(formatter, "Day | Part   | {:<width$} | {:>12} | {:>12}", "Answer", "Parse", "Solve")?;
+        writeln!(formatter, "----+--------+-{:-<width$}-+-{:->12}-+-{:->12}", "", "", "")?;
+        for outcome in self.outcomes {
+            writeln!(
+                formatter,
+                "{:>3} | {} | {:<width$} | {:>12} | {:>12}",
+                outcome.day,
+                outcome.task,
+                answer_text(outcome),
+                format!("{:.2?}", outcome.parse.median),
+                format!("{:.2?}", outcome.solve.median),
+            )?;
+        }
+
+        if self.outcomes.iter().all(|outcome| outcome.solve.samples <= 1) {
+            return Ok(());
+        }
+
+        writeln!(formatter)?;
+        writeln!(
+            formatter,
+            "Day | Part   | Stage | {:>12} | {:>12} | {:>12} | {:>12} | Samples",
+            "Min", "Median", "Mean", "P99"
+        )?;
+        writeln!(formatter, "----+--------+-------+-{:->12}-+-{:->12}-+-{:->12}-+-{:->12}-+--------", "", "", "", "")?;
+        for outcome in self.outcomes {
+            for (stage, timing) in [("parse", &outcome.parse), ("solve", &outcome.solve)] {
+                writeln!(
+                    formatter,
+                    "{:>3} | {} | {stage} | {:>12} | {:>12} | {:>12} | {:>12} | {}",
+                    outcome.day,
+                    outcome.task,
+                    format!("{:.2?}", timing.min),
+                    format!("{:.2?}", timing.median),
+                    format!("{:.2?}", timing.mean),
+                    format!("{:.2?}", timing.p99),
+                    timing.samples,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// One JSON object per line with durations in nanoseconds, a failed part has a null answer and an error
+    fn json(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        for outcome in self.outcomes {
+            let answer = match &outcome.answer {
+                Ok(answer) => format!("\"answer\":\"{}\"", json_escape(answer)),
+                Err(error) => format!("\"answer\":null,\"error\":\"{}\"", json_escape(&error.to_string())),
+            };
+            write!(
+                formatter,
+                "{{\"day\":{},\"part\":{},{answer},\"iterations\":{}",
+                outcome.day,
+                outcome.task.number(),
+                outcome.solve.samples,
+            )?;
+            for (stage, timing) in [("parse", &outcome.parse), ("solve", &outcome.solve)] {
+                write!(
+                    formatter,
+                    ",\"{stage}_min_ns\":{},\"{stage}_median_ns\":{},\"{stage}_mean_ns\":{},\"{stage}_p99_ns\":{}",
+                    timing.min.as_nanos(),
+                    timing.median.as_nanos(),
+                    timing.mean.as_nanos(),
+                    timing.p99.as_nanos(),
+                )?;
+            }
+            writeln!(formatter, "}}")?;
+        }
+
+        Ok(())
+    }
+
+    fn csv(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        writeln!(formatter, "{CSV_HEADER}")?;
+        for outcome in self.outcomes {
+            writeln!(formatter, "{}", csv_record(outcome))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Answer as shown in the table, failed parts only say so since the error itself goes to stderr
+fn answer_text(outcome: &Outcome) -> &str {
+    outcome.answer.as_deref().unwrap_or("failed")
+}
+
+/// Quotes, backslashes and control characters escaped so every outcome stays on a single JSON line
+fn json_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for char in text.chars() {
+        match char {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            '\u{0}'..='\u{1f}' => escaped.push_str(&format!("\\u{:04x}", char as u32)),
+            _ => escaped.push(char),
+        }
+    }
+
+    escaped
+}
+
+/// Failed parts leave the answer empty
+pub(crate) fn csv_record(outcome: &Outcome) -> String {
+    let answer = match outcome.answer.as_deref().unwrap_or_default() {
+        answer if answer.contains([',', '"', '\n']) => format!("\"{}\"", answer.replace('"', "\"\"")),
+        answer => answer.to_string(),
+    };
+
+    let mut record = format!("{},{},{answer},{}", outcome.day, outcome.task.number(), outcome.solve.samples);
+    for timing in [&outcome.parse, &outcome.solve] {
+        record.push_str(&format!(
+            ",{},{},{},{}",
+            timing.min.as_nanos(),
+            timing.median.as_nanos(),
+            timing.mean.as_nanos(),
+            timing.p99.as_nanos(),
+        ));
+    }
+
+    record
+}
+
+pub(crate) const CSV_HEADER: &str = "day,part,answer,iterations,parse_min_ns,parse_median_ns,parse_mean_ns,parse_p99_ns,solve_min_ns,solve_median_ns,solve_mean_ns,solve_p99_ns";
+
+impl Display for Report<'_> {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self.format {
+            Format::Table => self.table(formatter),
+            Format::Json => self.json(formatter),
+            Format::Csv => self.csv(formatter),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::*;
+
+    struct Failing;
+
+    impl Solution for Failing {
+        const DAY: u8 = 3;
+        type Input<'a> = u64;
+
+        fn parse(_input: &[u8]) -> Result<Self::Input<'_>, Error> {
+            Ok(7)
+        }
+
+        fn part1(input: &Self::Input<'_>) -> Result<impl Display, Error> {
+            Ok(*input)
+        }
+
+        fn part2(_input: &Self::Input<'_>) -> Option<Result<impl Display, Error>> {
+            Some(Err::<u64, _>(Error::new("no answer")))
+        }
+    }
+
+    #[test]
+    fn keeps_solved_parts_when_another_fails() {
+        let outcomes = run::<Failing>(b"", &[Task::Part1, Task::Part2], Bench::ONCE).unwrap();
+
+        assert_eq!(outcomes.iter().map(|outcome| outcome.answer.clone()).collect::<Vec<_>>(), [
+            Ok("7".into()),
+            Err(Error::new("no answer").in_day(3)),
+        ]);
+        assert_eq!(
+            Report::new(&outcomes, Format::Table).to_string().lines().skip(2).map(|line| &line[..21]).collect::<Vec<_>>(),
+            ["  3 | Part 1 | 7     ", "  3 | Part 2 | failed"]
+        );
+        assert!(Report::new(&outcomes[1..], Format::Json).to_string().starts_with("{\"day\":3,\"part\":2,\"answer\":null,\"error\":\"day3: no answer\","));
+        assert!(csv_record(&outcomes[1]).starts_with("3,2,,1,"));
+    }
+
+    #[test]
+    fn renders_results_table() {
+        let outcomes = [
+            Outcome { day: 1, task: Task::Part1, answer: Ok("1150".into()), parse: Statistics::single(Duration::from_micros(12)), solve: Statistics::single(Duration::from_nanos(800)) },
+            Outcome { day: 12, task: Task::Part2, answer: Ok("42".into()), parse: Statistics::default(), solve: Statistics::single(Duration::from_millis(3)) },
+        ];
+
+        assert_eq!(
+            Report::new(&outcomes, Format::Table).to_string(),
+            "Day | Part   | Answer |        Parse |        Solve
+----+--------+--------+--------------+-------------
+  1 | Part 1 | 1150   |      12.00µs |     800.00ns
+ 12 | Part 2 | 42     |       0.00ns |       3.00ms
+"
+        );
+    }
+
+    #[test]
+    fn renders_timing_breakdown_for_benchmarks() {
+        let timing = Statistics {
+            samples: 100,
+            min: Duration::from_nanos(900),
+            median: Duration::from_micros(1),
+            mean: Duration::from_nanos(1_250),
+            p99: Duration::from_micros(4),
+        };
+        let outcomes = [
+            Outcome { day: 5, task: Task::Part1, answer: Ok("3".into()), parse: timing, solve: timing },
+        ];
+
+        assert_eq!(
+            Report::new(&outcomes, Format::Table).to_string(),
+            "Day | Part   | Answer |        Parse |        Solve
+----+--------+--------+--------------+-------------
+  5 | Part 1 | 3      |       1.00µs |       1.00µs
+
+Day | Part   | Stage |          Min |       Median |         Mean |          P99 | Samples
+----+--------+-------+--------------+--------------+--------------+--------------+--------
+  5 | Part 1 | parse |     900.00ns |       1.00µs |       1.25µs |       4.00µs | 100
+  5 | Part 1 | solve |     900.00ns |       1.00µs |       1.25µs |       4.00µs | 100
+"
+        );
+    }
+
+    #[test]
+    fn renders_json_lines() {
+        let outcomes = [
+            Outcome { day: 7, task: Task::Part2, answer: Ok("40".into()), parse: Statistics::single(Duration::from_nanos(5)), solve: Statistics::single(Duration::from_micros(2)) },
+        ];
+
+        assert_eq!(
+            Report::new(&outcomes, Format::Json).to_string(),
+            "{\"day\":7,\"part\":2,\"answer\":\"40\",\"iterations\":1,\"parse_min_ns\":5,\"parse_median_ns\":5,\"parse_mean_ns\":5,\"parse_p99_ns\":5,\"solve_min_ns\":2000,\"solve_median_ns\":2000,\"solve_mean_ns\":2000,\"solve_p99_ns\":2000}\n"
+        );
+    }
+
+    #[test]
+    fn escapes_json_strings() {
+        assert_eq!(json_escape("a\"b\\c"), "a\\\"b\\\\c");
+        assert_eq!(json_escape("#.\n.#\r\t\u{0}\u{1b}é"), "#.\\n.#\\r\\t\\u0000\\u001bé");
+    }
+
+    #[test]
+    fn renders_csv_with_quoted_answers() {
+        let outcomes = [
+            Outcome { day: 1, task: Task::Part1, answer: Ok("3".into()), parse: Statistics::default(), solve: Statistics::single(Duration::from_nanos(10)) },
+            Outcome { day: 2, task: Task::Part1, answer: Ok("6,5".into()), parse: Statistics::default(), solve: Statistics::default() },
+        ];
+
+        assert_eq!(
+            Report::new(&outcomes, Format::Csv).to_string(),
+            format!("{CSV_HEADER}\n1,1,3,1,0,0,0,0,10,10,10,10\n2,1,\"6,5\",0,0,0,0,0,0,0,0,0\n")
+        );
+    }
+}